@@ -0,0 +1,109 @@
+use core::ops::RangeInclusive;
+
+use utils::{
+    reader::Reader,
+    writer::{WriteError, Writer},
+};
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part F, Section 3.2.2
+// The attribute handle 0x0000 is reserved, valid handles range from 0x0001 to 0xFFFF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandleRange {
+    start: u16,
+    end: u16,
+}
+
+impl HandleRange {
+    pub const FULL: Self = Self {
+        start: 0x0001,
+        end: 0xFFFF,
+    };
+
+    pub const fn new(start: u16, end: u16) -> Option<Self> {
+        if start == 0x0000 || start > end {
+            return None;
+        }
+
+        Some(Self { start, end })
+    }
+
+    pub const fn start(&self) -> u16 {
+        self.start
+    }
+
+    pub const fn end(&self) -> u16 {
+        self.end
+    }
+
+    pub const fn contains(&self, handle: u16) -> bool {
+        self.start <= handle && handle <= self.end
+    }
+
+    pub fn read(reader: &mut Reader) -> Option<Self> {
+        Self::new(reader.read_u16()?, reader.read_u16()?)
+    }
+
+    pub fn write(&self, writer: &mut Writer) -> Result<(), WriteError> {
+        writer.write_u16(self.start)?;
+        writer.write_u16(self.end)
+    }
+}
+
+impl IntoIterator for HandleRange {
+    type Item = u16;
+    type IntoIter = RangeInclusive<u16>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..=self.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_handle_range() {
+        let range = HandleRange::new(0x0001, 0x0003).unwrap();
+
+        assert!(range.contains(0x0002));
+        assert!(!range.contains(0x0004));
+
+        let mut handles = range.into_iter();
+        assert_eq!(handles.next(), Some(0x0001));
+        assert_eq!(handles.next(), Some(0x0002));
+        assert_eq!(handles.next(), Some(0x0003));
+        assert_eq!(handles.next(), None);
+
+        let single = HandleRange::new(0xFFFF, 0xFFFF).unwrap();
+        assert_eq!(single.into_iter().count(), 1);
+        assert_eq!(HandleRange::FULL.into_iter().count(), 0xFFFF);
+    }
+
+    #[test]
+    fn test_invalid_handle_range() {
+        assert_eq!(HandleRange::new(0x0000, 0x0005), None);
+        assert_eq!(HandleRange::new(0x0006, 0x0005), None);
+    }
+
+    #[test]
+    fn test_handle_range_read_write() {
+        let mut buf = [0; 8];
+        let mut writer = Writer::new(&mut buf);
+
+        HandleRange::new(0x0010, 0x00FF)
+            .unwrap()
+            .write(&mut writer)
+            .unwrap();
+        assert_eq!(&buf[..4], &[0x10, 0x00, 0xFF, 0x00]);
+
+        let mut reader = Reader::new(&buf[..4]);
+        assert_eq!(
+            HandleRange::read(&mut reader),
+            HandleRange::new(0x0010, 0x00FF)
+        );
+
+        let mut reader = Reader::new(&[0x00, 0x00, 0xFF, 0xFF]);
+        assert_eq!(HandleRange::read(&mut reader), None);
+    }
+}
@@ -0,0 +1,5 @@
+mod handle;
+mod pdu;
+
+pub use handle::*;
+pub use pdu::*;
@@ -0,0 +1,703 @@
+use macros::{IntoU8, TryFromU8};
+use utils::{
+    reader::Reader,
+    writer::{WriteError, Writer},
+};
+
+use super::HandleRange;
+use crate::hci::{ReadUuid, Uuid};
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part F, Section 3.4.8
+// Attribute Protocol PDUs method overview
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoU8, TryFromU8)]
+#[repr(u8)]
+pub enum AttOpcode {
+    ErrorResponse = 0x01,           // 3.4.1.1
+    ExchangeMTURequest = 0x02,      // 3.4.2.1
+    ExchangeMTUResponse = 0x03,     // 3.4.2.2
+    FindInformationRequest = 0x04,  // 3.4.3.1
+    FindInformationResponse = 0x05, // 3.4.3.2
+    FindByTypeValueRequest = 0x06,  // 3.4.3.3
+    FindByTypeValueResponse = 0x07, // 3.4.3.4
+    ReadByTypeRequest = 0x08,       // 3.4.4.1
+    ReadByTypeResponse = 0x09,      // 3.4.4.2
+    ReadRequest = 0x0A,             // 3.4.4.3
+    ReadResponse = 0x0B,            // 3.4.4.4
+    ReadBlobRequest = 0x0C,         // 3.4.4.5
+    ReadBlobResponse = 0x0D,        // 3.4.4.6
+    ReadMultipleRequest = 0x0E,     // 3.4.4.7
+    ReadMultipleResponse = 0x0F,    // 3.4.4.8
+    ReadByGroupTypeRequest = 0x10,  // 3.4.4.9
+    ReadByGroupTypeResponse = 0x11, // 3.4.4.10
+    WriteRequest = 0x12,            // 3.4.5.1
+    WriteResponse = 0x13,           // 3.4.5.2
+    PrepareWriteRequest = 0x16,     // 3.4.6.1
+    PrepareWriteResponse = 0x17,    // 3.4.6.2
+    ExecuteWriteRequest = 0x18,     // 3.4.6.3
+    ExecuteWriteResponse = 0x19,    // 3.4.6.4
+    HandleValueNotification = 0x1B, // 3.4.7.1
+    HandleValueIndication = 0x1D,   // 3.4.7.2
+    HandleValueConfirmation = 0x1E, // 3.4.7.3
+    WriteCommand = 0x52,            // 3.4.5.3
+    SignedWriteCommand = 0xD2,      // 3.4.5.4
+}
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part G, Section 5.2.1
+// ATT_MTU used on an LE link until it is changed by an MTU exchange
+pub const ATT_DEFAULT_MTU: u16 = 23;
+
+// 3.4.6.3 Execute Write Request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoU8)]
+#[repr(u8)]
+pub enum ExecuteWriteFlags {
+    CancelAll = 0x00,
+    WriteAll = 0x01,
+}
+
+#[derive(Debug)]
+pub enum AttRequest<'p> {
+    FindInformation {
+        range: HandleRange,
+    }, // 3.4.3.1
+    FindByTypeValue {
+        range: HandleRange,
+        attribute_type: u16,
+        attribute_value: &'p [u8],
+    }, // 3.4.3.3
+    ReadByType {
+        range: HandleRange,
+        attribute_type: Uuid,
+    }, // 3.4.4.1
+    Read {
+        attribute_handle: u16,
+    }, // 3.4.4.3
+    ReadBlob {
+        attribute_handle: u16,
+        value_offset: u16,
+    }, // 3.4.4.5
+    ReadMultiple {
+        attribute_handles: &'p [u16],
+    }, // 3.4.4.7
+    Write {
+        attribute_handle: u16,
+        attribute_value: &'p [u8],
+    }, // 3.4.5.1
+    WriteCommand {
+        attribute_handle: u16,
+        attribute_value: &'p [u8],
+    }, // 3.4.5.3
+    PrepareWrite {
+        attribute_handle: u16,
+        value_offset: u16,
+        part_attribute_value: &'p [u8],
+    }, // 3.4.6.1
+    ExecuteWrite {
+        flags: ExecuteWriteFlags,
+    }, // 3.4.6.3
+}
+
+impl AttRequest<'_> {
+    /// Writes the PDU into `buf`, failing with [`WriteError::InvalidFormat`] if it does not fit
+    /// into a single PDU of the negotiated `mtu`.
+    pub fn write_into(&self, buf: &mut [u8], mtu: u16) -> Result<usize, WriteError> {
+        let mut writer = Writer::new(buf);
+
+        match self {
+            Self::FindInformation { range } => {
+                writer.write_u8(AttOpcode::FindInformationRequest.into())?;
+                range.write(&mut writer)?;
+            }
+            Self::FindByTypeValue {
+                range,
+                attribute_type,
+                attribute_value,
+            } => {
+                writer.write_u8(AttOpcode::FindByTypeValueRequest.into())?;
+                range.write(&mut writer)?;
+                writer.write_u16(*attribute_type)?;
+                writer.write_u8_slice(attribute_value)?;
+            }
+            Self::ReadByType {
+                range,
+                attribute_type,
+            } => {
+                writer.write_u8(AttOpcode::ReadByTypeRequest.into())?;
+                range.write(&mut writer)?;
+
+                // The attribute type is either a 16-bit or a 128-bit UUID, 32-bit UUIDs are sent
+                // in their 128-bit form
+                match attribute_type {
+                    Uuid::Uuid16(uuid) => writer.write_u16(*uuid)?,
+                    uuid => writer.write_u128(uuid.to_u128())?,
+                }
+            }
+            Self::Read { attribute_handle } => {
+                writer.write_u8(AttOpcode::ReadRequest.into())?;
+                writer.write_u16(*attribute_handle)?;
+            }
+            Self::ReadBlob {
+                attribute_handle,
+                value_offset,
+            } => {
+                writer.write_u8(AttOpcode::ReadBlobRequest.into())?;
+                writer.write_u16(*attribute_handle)?;
+                writer.write_u16(*value_offset)?;
+            }
+            Self::ReadMultiple { attribute_handles } => {
+                writer.write_u8(AttOpcode::ReadMultipleRequest.into())?;
+                for handle in attribute_handles.iter() {
+                    writer.write_u16(*handle)?;
+                }
+            }
+            Self::Write {
+                attribute_handle,
+                attribute_value,
+            } => {
+                writer.write_u8(AttOpcode::WriteRequest.into())?;
+                writer.write_u16(*attribute_handle)?;
+                writer.write_u8_slice(attribute_value)?;
+            }
+            Self::WriteCommand {
+                attribute_handle,
+                attribute_value,
+            } => {
+                writer.write_u8(AttOpcode::WriteCommand.into())?;
+                writer.write_u16(*attribute_handle)?;
+                writer.write_u8_slice(attribute_value)?;
+            }
+            Self::PrepareWrite {
+                attribute_handle,
+                value_offset,
+                part_attribute_value,
+            } => {
+                writer.write_u8(AttOpcode::PrepareWriteRequest.into())?;
+                writer.write_u16(*attribute_handle)?;
+                writer.write_u16(*value_offset)?;
+                writer.write_u8_slice(part_attribute_value)?;
+            }
+            Self::ExecuteWrite { flags } => {
+                writer.write_u8(AttOpcode::ExecuteWriteRequest.into())?;
+                writer.write_u8((*flags).into())?;
+            }
+        }
+
+        if writer.pos > mtu as usize {
+            return Err(WriteError::InvalidFormat);
+        }
+
+        Ok(writer.pos)
+    }
+}
+
+#[derive(Debug)]
+pub enum AttResponse<'p> {
+    Error(ErrorResponse),                            // 3.4.1.1
+    FindInformation(FindInformationIterator<'p>),    // 3.4.3.2
+    FindByTypeValue(HandlesInformationIterator<'p>), // 3.4.3.4
+    ReadByType(ReadByTypeIterator<'p>),              // 3.4.4.2
+    Read {
+        attribute_value: &'p [u8],
+    },   // 3.4.4.4
+    ReadBlob {
+        part_attribute_value: &'p [u8],
+    }, // 3.4.4.6
+    ReadMultiple {
+        set_of_values: &'p [u8],
+    }, // 3.4.4.8
+    Write,                                           // 3.4.5.2
+    PrepareWrite {
+        attribute_handle: u16,
+        value_offset: u16,
+        part_attribute_value: &'p [u8],
+    }, // 3.4.6.2
+    ExecuteWrite,                                    // 3.4.6.4
+}
+
+impl<'p> AttResponse<'p> {
+    pub fn from_buf(buf: &'p [u8]) -> Option<AttResponse<'p>> {
+        let mut reader = Reader::new(buf);
+        let opcode = AttOpcode::try_from(reader.read_u8()?).ok()?;
+
+        Some(match opcode {
+            AttOpcode::ErrorResponse => Self::Error(ErrorResponse {
+                request_opcode: reader.read_u8()?,
+                attribute_handle: reader.read_u16()?,
+                error_code: reader.read_u8()?,
+            }),
+            AttOpcode::FindInformationResponse => {
+                let format = reader.read_u8()?;
+
+                if format != FindInformationIterator::FORMAT_UUID16
+                    && format != FindInformationIterator::FORMAT_UUID128
+                {
+                    return None;
+                }
+
+                Self::FindInformation(FindInformationIterator {
+                    format,
+                    reader: reader.sub_reader(reader.remaining())?,
+                })
+            }
+            AttOpcode::FindByTypeValueResponse => {
+                Self::FindByTypeValue(HandlesInformationIterator {
+                    reader: reader.sub_reader(reader.remaining())?,
+                })
+            }
+            AttOpcode::ReadByTypeResponse => {
+                let length = reader.read_u8()?;
+
+                if (length as usize) < size_of::<u16>() {
+                    return None;
+                }
+
+                Self::ReadByType(ReadByTypeIterator {
+                    length,
+                    reader: reader.sub_reader(reader.remaining())?,
+                })
+            }
+            AttOpcode::ReadResponse => Self::Read {
+                attribute_value: reader.read_u8_slice(reader.remaining())?,
+            },
+            AttOpcode::ReadBlobResponse => Self::ReadBlob {
+                part_attribute_value: reader.read_u8_slice(reader.remaining())?,
+            },
+            AttOpcode::ReadMultipleResponse => Self::ReadMultiple {
+                set_of_values: reader.read_u8_slice(reader.remaining())?,
+            },
+            AttOpcode::WriteResponse => Self::Write,
+            AttOpcode::PrepareWriteResponse => Self::PrepareWrite {
+                attribute_handle: reader.read_u16()?,
+                value_offset: reader.read_u16()?,
+                part_attribute_value: reader.read_u8_slice(reader.remaining())?,
+            },
+            AttOpcode::ExecuteWriteResponse => Self::ExecuteWrite,
+            opcode => {
+                log::warn!("{:?} is not implemented skipping", opcode);
+                return None;
+            }
+        })
+    }
+}
+
+// 3.4.1.1 Error Response
+#[derive(Debug)]
+pub struct ErrorResponse {
+    pub request_opcode: u8,
+    pub attribute_handle: u16,
+    pub error_code: u8,
+}
+
+// 3.4.3.2 Find Information Response
+// The format applies to every handle-UUID pair in the response.
+#[derive(Debug)]
+pub struct FindInformationIterator<'p> {
+    pub format: u8,
+    pub reader: Reader<'p>,
+}
+
+impl FindInformationIterator<'_> {
+    pub const FORMAT_UUID16: u8 = 0x01;
+    pub const FORMAT_UUID128: u8 = 0x02;
+}
+
+impl Iterator for FindInformationIterator<'_> {
+    type Item = (u16, Uuid);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.remaining() == 0 {
+            return None;
+        }
+
+        let handle = self.reader.read_u16()?;
+        let uuid = match self.format {
+            Self::FORMAT_UUID16 => self.reader.read_uuid16()?,
+            _ => self.reader.read_uuid128()?,
+        };
+
+        Some((handle, uuid))
+    }
+}
+
+// 3.4.3.4 Find By Type Value Response
+// Each entry is the Found Attribute Handle followed by its Group End Handle.
+#[derive(Debug)]
+pub struct HandlesInformationIterator<'p> {
+    pub reader: Reader<'p>,
+}
+
+impl Iterator for HandlesInformationIterator<'_> {
+    type Item = HandleRange;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.remaining() == 0 {
+            return None;
+        }
+
+        HandleRange::read(&mut self.reader)
+    }
+}
+
+// 3.4.4.2 Read By Type Response
+// Every entry is an attribute handle followed by its value, the length applies to all of them.
+#[derive(Debug)]
+pub struct ReadByTypeIterator<'p> {
+    pub length: u8,
+    pub reader: Reader<'p>,
+}
+
+impl<'p> Iterator for ReadByTypeIterator<'p> {
+    type Item = (u16, &'p [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.remaining() == 0 {
+            return None;
+        }
+
+        let handle = self.reader.read_u16()?;
+        let value = self
+            .reader
+            .read_u8_slice(self.length as usize - size_of::<u16>())?;
+
+        Some((handle, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_information_request() {
+        let mut buf = [0; 8];
+        let request = AttRequest::FindInformation {
+            range: HandleRange::new(0x0001, 0xFFFF).unwrap(),
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..len], &[0x04, 0x01, 0x00, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn test_find_by_type_value_request() {
+        let mut buf = [0; 16];
+        let request = AttRequest::FindByTypeValue {
+            range: HandleRange::FULL,
+            attribute_type: 0x2800,
+            attribute_value: &[0x0F, 0x18],
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(
+            &buf[..len],
+            &[0x06, 0x01, 0x00, 0xFF, 0xFF, 0x00, 0x28, 0x0F, 0x18]
+        );
+    }
+
+    #[test]
+    fn test_find_information_response_uuid16() {
+        let buf = [0x05, 0x01, 0x03, 0x00, 0x02, 0x29, 0x04, 0x00, 0x01, 0x29];
+
+        let Some(AttResponse::FindInformation(mut information)) = AttResponse::from_buf(&buf)
+        else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(information.next(), Some((0x0003, Uuid::Uuid16(0x2902))));
+        assert_eq!(information.next(), Some((0x0004, Uuid::Uuid16(0x2901))));
+        assert_eq!(information.next(), None);
+    }
+
+    #[test]
+    fn test_find_information_response_uuid128() {
+        let buf = [
+            0x05, 0x02, 0x10, 0x00, 0xFB, 0x34, 0x9B, 0x5F, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10,
+            0x00, 0x00, 0x02, 0x29, 0x00, 0x00,
+        ];
+
+        let Some(AttResponse::FindInformation(mut information)) = AttResponse::from_buf(&buf)
+        else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(
+            information.next(),
+            Some((
+                0x0010,
+                Uuid::Uuid128(0x00002902_0000_1000_8000_00805F9B34FB)
+            ))
+        );
+        assert_eq!(information.next(), None);
+    }
+
+    #[test]
+    fn test_find_information_response_invalid_format() {
+        let buf = [0x05, 0x03, 0x03, 0x00, 0x02, 0x29];
+
+        assert!(AttResponse::from_buf(&buf).is_none());
+    }
+
+    #[test]
+    fn test_find_by_type_value_response() {
+        let buf = [0x07, 0x01, 0x00, 0x05, 0x00, 0x10, 0x00, 0x14, 0x00];
+
+        let Some(AttResponse::FindByTypeValue(mut handles)) = AttResponse::from_buf(&buf) else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(handles.next(), HandleRange::new(0x0001, 0x0005));
+        assert_eq!(handles.next(), HandleRange::new(0x0010, 0x0014));
+        assert_eq!(handles.next(), None);
+    }
+
+    #[test]
+    fn test_read_by_type_request() {
+        let mut buf = [0; 32];
+        let request = AttRequest::ReadByType {
+            range: HandleRange::new(0x0001, 0x000B).unwrap(),
+            attribute_type: Uuid::Uuid16(0x2803),
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..len], &[0x08, 0x01, 0x00, 0x0B, 0x00, 0x03, 0x28]);
+
+        let request = AttRequest::ReadByType {
+            range: HandleRange::FULL,
+            attribute_type: Uuid::Uuid32(0x00002A00),
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x08, 0x01, 0x00, 0xFF, 0xFF, 0xFB, 0x34, 0x9B, 0x5F, 0x80, 0x00, 0x00, 0x80, 0x00,
+                0x10, 0x00, 0x00, 0x00, 0x2A, 0x00, 0x00
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_by_type_response() {
+        // Two characteristic declarations, properties, value handle and 16-bit UUID each
+        let buf = [
+            0x09, 0x07, 0x02, 0x00, 0x02, 0x03, 0x00, 0x00, 0x2A, 0x04, 0x00, 0x12, 0x05, 0x00,
+            0x37, 0x2A,
+        ];
+
+        let Some(AttResponse::ReadByType(mut attributes)) = AttResponse::from_buf(&buf) else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(attributes.length, 7);
+        assert_eq!(
+            attributes.next(),
+            Some((0x0002, &[0x02, 0x03, 0x00, 0x00, 0x2A][..]))
+        );
+        assert_eq!(
+            attributes.next(),
+            Some((0x0004, &[0x12, 0x05, 0x00, 0x37, 0x2A][..]))
+        );
+        assert_eq!(attributes.next(), None);
+    }
+
+    #[test]
+    fn test_read_by_type_response_invalid_length() {
+        assert!(AttResponse::from_buf(&[0x09, 0x01, 0x02]).is_none());
+
+        let Some(AttResponse::ReadByType(mut attributes)) =
+            AttResponse::from_buf(&[0x09, 0x03, 0x02, 0x00, 0x64, 0x03, 0x00])
+        else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(attributes.next(), Some((0x0002, &[0x64][..])));
+        assert_eq!(attributes.next(), None);
+    }
+
+    #[test]
+    fn test_read_request() {
+        let mut buf = [0; 8];
+        let request = AttRequest::Read {
+            attribute_handle: 0x0003,
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..len], &[0x0A, 0x03, 0x00]);
+    }
+
+    #[test]
+    fn test_read_blob_at_offset() {
+        let mut buf = [0; 8];
+        let request = AttRequest::ReadBlob {
+            attribute_handle: 0x0003,
+            value_offset: 22,
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..len], &[0x0C, 0x03, 0x00, 0x16, 0x00]);
+
+        let buf = [0x0D, b'l', b'd', b'!'];
+
+        let Some(AttResponse::ReadBlob {
+            part_attribute_value,
+        }) = AttResponse::from_buf(&buf)
+        else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(part_attribute_value, b"ld!");
+    }
+
+    #[test]
+    fn test_read_multiple_request() {
+        let mut buf = [0; 8];
+        let request = AttRequest::ReadMultiple {
+            attribute_handles: &[0x0003, 0x0010],
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..len], &[0x0E, 0x03, 0x00, 0x10, 0x00]);
+    }
+
+    #[test]
+    fn test_read_responses() {
+        let buf = [0x0B, 0x64];
+
+        let Some(AttResponse::Read { attribute_value }) = AttResponse::from_buf(&buf) else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(attribute_value, &[0x64]);
+
+        let buf = [0x0F, 0x64, 0x01, 0x02];
+
+        let Some(AttResponse::ReadMultiple { set_of_values }) = AttResponse::from_buf(&buf) else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(set_of_values, &[0x64, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_write_request() {
+        let mut buf = [0; 8];
+        let request = AttRequest::Write {
+            attribute_handle: 0x0012,
+            attribute_value: &[0x01, 0x00],
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..len], &[0x12, 0x12, 0x00, 0x01, 0x00]);
+        assert!(matches!(
+            AttResponse::from_buf(&[0x13]),
+            Some(AttResponse::Write)
+        ));
+    }
+
+    #[test]
+    fn test_write_command() {
+        let mut buf = [0; 8];
+        let request = AttRequest::WriteCommand {
+            attribute_handle: 0x0012,
+            attribute_value: &[0x01],
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..len], &[0x52, 0x12, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn test_write_exceeding_mtu() {
+        let mut buf = [0; 64];
+        let value = [0xAB; ATT_DEFAULT_MTU as usize - 2];
+        let request = AttRequest::Write {
+            attribute_handle: 0x0012,
+            attribute_value: &value,
+        };
+
+        assert!(matches!(
+            request.write_into(&mut buf, ATT_DEFAULT_MTU),
+            Err(WriteError::InvalidFormat)
+        ));
+        assert!(request.write_into(&mut buf, ATT_DEFAULT_MTU + 1).is_ok());
+    }
+
+    #[test]
+    fn test_prepared_write() {
+        let value: [u8; 30] = core::array::from_fn(|i| i as u8);
+        let (first, second) = value.split_at(ATT_DEFAULT_MTU as usize - 5);
+
+        let mut buf = [0; 32];
+        let request = AttRequest::PrepareWrite {
+            attribute_handle: 0x0020,
+            value_offset: 0,
+            part_attribute_value: first,
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(len, ATT_DEFAULT_MTU as usize);
+        assert_eq!(&buf[..5], &[0x16, 0x20, 0x00, 0x00, 0x00]);
+        assert_eq!(&buf[5..len], first);
+
+        // The server echoes the request in its response
+        buf[0] = AttOpcode::PrepareWriteResponse.into();
+
+        let Some(AttResponse::PrepareWrite {
+            attribute_handle,
+            value_offset,
+            part_attribute_value,
+        }) = AttResponse::from_buf(&buf[..len])
+        else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(attribute_handle, 0x0020);
+        assert_eq!(value_offset, 0);
+        assert_eq!(part_attribute_value, first);
+
+        let request = AttRequest::PrepareWrite {
+            attribute_handle: 0x0020,
+            value_offset: first.len() as u16,
+            part_attribute_value: second,
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..5], &[0x16, 0x20, 0x00, 0x12, 0x00]);
+        assert_eq!(&buf[5..len], second);
+
+        let request = AttRequest::ExecuteWrite {
+            flags: ExecuteWriteFlags::WriteAll,
+        };
+
+        let len = request.write_into(&mut buf, ATT_DEFAULT_MTU).unwrap();
+
+        assert_eq!(&buf[..len], &[0x18, 0x01]);
+        assert!(matches!(
+            AttResponse::from_buf(&[0x19]),
+            Some(AttResponse::ExecuteWrite)
+        ));
+    }
+
+    #[test]
+    fn test_error_response() {
+        let buf = [0x01, 0x04, 0x01, 0x00, 0x0A];
+
+        let Some(AttResponse::Error(error)) = AttResponse::from_buf(&buf) else {
+            panic!("Unexpected response");
+        };
+
+        assert_eq!(error.request_opcode, 0x04);
+        assert_eq!(error.attribute_handle, 0x0001);
+        assert_eq!(error.error_code, 0x0A);
+    }
+}
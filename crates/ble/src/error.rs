@@ -0,0 +1,124 @@
+use core::fmt::Display;
+
+use utils::{reader::ReadError, writer::WriteError};
+
+use crate::hci::HciParseError;
+#[cfg(feature = "transport")]
+use crate::transport::TransportError;
+
+#[derive(Debug)]
+pub enum Error {
+    Read(ReadError),
+    Write(WriteError),
+    Parse(HciParseError),
+    #[cfg(feature = "transport")]
+    Transport(TransportError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Read(error) => write!(f, "ReadError: {error}"),
+            Self::Write(error) => write!(f, "WriteError: {error}"),
+            Self::Parse(error) => write!(f, "HciParseError: {error}"),
+            #[cfg(feature = "transport")]
+            Self::Transport(error) => write!(f, "TransportError: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Read(error) => Some(error),
+            Self::Write(error) => Some(error),
+            Self::Parse(error) => Some(error),
+            #[cfg(feature = "transport")]
+            Self::Transport(error) => Some(error),
+        }
+    }
+}
+
+impl From<ReadError> for Error {
+    fn from(error: ReadError) -> Self {
+        Self::Read(error)
+    }
+}
+
+impl From<WriteError> for Error {
+    fn from(error: WriteError) -> Self {
+        Self::Write(error)
+    }
+}
+
+impl From<HciParseError> for Error {
+    fn from(error: HciParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+#[cfg(feature = "transport")]
+impl From<TransportError> for Error {
+    fn from(error: TransportError) -> Self {
+        Self::Transport(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hci::{HCIEvent, HCIEventCode, HCIEventPacket, commands};
+    use utils::reader::Reader;
+
+    fn read_name(buf: &[u8]) -> Result<&str, Error> {
+        Ok(Reader::new(buf).read_str_u8_prefixed()?)
+    }
+
+    fn write_cancel(buf: &mut [u8]) -> Result<usize, Error> {
+        Ok(commands::le_create_connection_cancel().write_into(buf)?)
+    }
+
+    fn parse_disconnection(parameters: &[u8]) -> Result<u8, Error> {
+        let packet = HCIEventPacket::new(
+            HCIEventCode::DisconnectionComplete.into(),
+            parameters.len(),
+            parameters,
+        );
+
+        match HCIEvent::from_packet(&packet)? {
+            HCIEvent::DisconnectionComplete(event) => Ok(event.reason),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_propagate_read_error() {
+        assert!(matches!(
+            read_name(&[0x04, b'b']),
+            Err(Error::Read(ReadError::BufferUnderflow))
+        ));
+    }
+
+    #[test]
+    fn test_propagate_write_error() {
+        assert!(matches!(
+            write_cancel(&mut [0; 2]),
+            Err(Error::Write(WriteError::BufferOverflow))
+        ));
+    }
+
+    #[test]
+    fn test_propagate_parse_error() {
+        assert!(matches!(
+            parse_disconnection(&[0x00, 0x01]),
+            Err(Error::Parse(HciParseError::OutOfBounds {
+                field: "connection_handle",
+                ..
+            }))
+        ));
+        assert_eq!(
+            parse_disconnection(&[0x00, 0x01, 0x00, 0x13]).unwrap(),
+            0x13
+        );
+    }
+}
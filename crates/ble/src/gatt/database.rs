@@ -0,0 +1,223 @@
+use alloc::vec::Vec;
+
+use crate::{att::HandleRange, hci::Uuid};
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part G, Section 3.1 and 3.3.1
+pub const PRIMARY_SERVICE: Uuid = Uuid::Uuid16(0x2800);
+pub const CHARACTERISTIC: Uuid = Uuid::Uuid16(0x2803);
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part G, Section 3.3.1.1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CharacteristicProperties(u8);
+
+impl CharacteristicProperties {
+    pub const BROADCAST: Self = Self(0x01);
+    pub const READ: Self = Self(0x02);
+    pub const WRITE_WITHOUT_RESPONSE: Self = Self(0x04);
+    pub const WRITE: Self = Self(0x08);
+    pub const NOTIFY: Self = Self(0x10);
+    pub const INDICATE: Self = Self(0x20);
+    pub const AUTHENTICATED_SIGNED_WRITES: Self = Self(0x40);
+    pub const EXTENDED_PROPERTIES: Self = Self(0x80);
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attribute {
+    pub handle: u16,
+    pub attribute_type: Uuid,
+    pub value: Vec<u8>,
+}
+
+/// A server side attribute table, handles are assigned in registration order starting at 0x0001.
+///
+/// Services, their characteristics and descriptors have to be registered in the order they are
+/// supposed to appear in the table.
+#[derive(Debug, Default)]
+pub struct AttributeDatabase {
+    attributes: Vec<Attribute>,
+}
+
+impl AttributeDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+
+    /// Adds a primary service declaration and returns its handle.
+    pub fn add_service(&mut self, uuid: Uuid) -> Option<u16> {
+        let mut value = Vec::new();
+        write_uuid(&mut value, uuid);
+
+        self.push(PRIMARY_SERVICE, value)
+    }
+
+    /// Adds a characteristic declaration followed by its value and returns the value handle.
+    pub fn add_characteristic(
+        &mut self,
+        uuid: Uuid,
+        properties: CharacteristicProperties,
+        value: &[u8],
+    ) -> Option<u16> {
+        let value_handle = self.next_handle()?.checked_add(1)?;
+
+        let mut declaration = Vec::new();
+        declaration.push(properties.bits());
+        declaration.extend_from_slice(&value_handle.to_le_bytes());
+        write_uuid(&mut declaration, uuid);
+
+        self.push(CHARACTERISTIC, declaration)?;
+        self.push(uuid, value.into())
+    }
+
+    /// Adds a descriptor to the last registered characteristic and returns its handle.
+    pub fn add_descriptor(&mut self, uuid: Uuid, value: &[u8]) -> Option<u16> {
+        self.push(uuid, value.into())
+    }
+
+    /// Returns the attributes of the given type within `range`, as used to answer Read By Type
+    /// requests. The types are compared as 128-bit UUIDs, so a 16-bit type also matches its 128-bit
+    /// form.
+    pub fn find_by_type(
+        &self,
+        range: HandleRange,
+        attribute_type: Uuid,
+    ) -> impl Iterator<Item = &Attribute> {
+        self.attributes.iter().filter(move |attribute| {
+            range.contains(attribute.handle)
+                && attribute.attribute_type.to_u128() == attribute_type.to_u128()
+        })
+    }
+
+    fn next_handle(&self) -> Option<u16> {
+        u16::try_from(self.attributes.len() + 1).ok()
+    }
+
+    fn push(&mut self, attribute_type: Uuid, value: Vec<u8>) -> Option<u16> {
+        let handle = self.next_handle()?;
+
+        self.attributes.push(Attribute {
+            handle,
+            attribute_type,
+            value,
+        });
+
+        Some(handle)
+    }
+}
+
+// ATT only transfers 16 and 128-bit UUIDs, 32-bit UUIDs are expanded using the Bluetooth Base UUID.
+fn write_uuid(buf: &mut Vec<u8>, uuid: Uuid) {
+    match uuid {
+        Uuid::Uuid16(uuid) => buf.extend_from_slice(&uuid.to_le_bytes()),
+        Uuid::Uuid32(_) | Uuid::Uuid128(_) => buf.extend_from_slice(&uuid.to_u128().to_le_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BATTERY_SERVICE: Uuid = Uuid::Uuid16(0x180F);
+    const BATTERY_LEVEL: Uuid = Uuid::Uuid16(0x2A19);
+    const CLIENT_CHARACTERISTIC_CONFIGURATION: Uuid = Uuid::Uuid16(0x2902);
+
+    fn battery_service() -> AttributeDatabase {
+        let mut database = AttributeDatabase::new();
+
+        database.add_service(BATTERY_SERVICE).unwrap();
+        database
+            .add_characteristic(
+                BATTERY_LEVEL,
+                CharacteristicProperties::READ.union(CharacteristicProperties::NOTIFY),
+                &[100],
+            )
+            .unwrap();
+        database
+            .add_descriptor(CLIENT_CHARACTERISTIC_CONFIGURATION, &[0x00, 0x00])
+            .unwrap();
+
+        database
+    }
+
+    #[test]
+    fn test_handles_are_assigned_in_order() {
+        let database = battery_service();
+        let attributes = database.attributes();
+
+        assert_eq!(attributes.len(), 4);
+        assert_eq!(attributes[0].attribute_type, PRIMARY_SERVICE);
+        assert_eq!(attributes[0].value, [0x0F, 0x18]);
+        assert_eq!(attributes[1].attribute_type, CHARACTERISTIC);
+        assert_eq!(attributes[1].value, [0x12, 0x03, 0x00, 0x19, 0x2A]);
+        assert_eq!(attributes[2].handle, 0x0003);
+        assert_eq!(attributes[3].handle, 0x0004);
+    }
+
+    #[test]
+    fn test_find_by_type() {
+        let database = battery_service();
+
+        let mut levels = database.find_by_type(HandleRange::FULL, BATTERY_LEVEL);
+        let level = levels.next().unwrap();
+        assert_eq!(level.handle, 0x0003);
+        assert_eq!(level.value, [100]);
+        assert!(levels.next().is_none());
+
+        let mut characteristics = database.find_by_type(HandleRange::FULL, CHARACTERISTIC);
+        assert_eq!(characteristics.next().unwrap().handle, 0x0002);
+        assert!(characteristics.next().is_none());
+    }
+
+    #[test]
+    fn test_find_by_type_128_bit_form() {
+        let database = battery_service();
+        let characteristic = Uuid::Uuid128(CHARACTERISTIC.to_u128());
+
+        let mut characteristics = database.find_by_type(HandleRange::FULL, characteristic);
+        assert_eq!(characteristics.next().unwrap().handle, 0x0002);
+        assert!(characteristics.next().is_none());
+
+        let battery_level = Uuid::Uuid128(0x00002A19_0000_1000_8000_00805F9B34FB);
+        assert_eq!(
+            database
+                .find_by_type(HandleRange::FULL, battery_level)
+                .next()
+                .unwrap()
+                .handle,
+            0x0003
+        );
+    }
+
+    #[test]
+    fn test_find_by_type_respects_range() {
+        let database = battery_service();
+        let range = HandleRange::new(0x0004, 0xFFFF).unwrap();
+
+        assert!(database.find_by_type(range, BATTERY_LEVEL).next().is_none());
+        assert_eq!(
+            database
+                .find_by_type(range, CLIENT_CHARACTERISTIC_CONFIGURATION)
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use core::fmt::Display;
+
+use super::HCIACLDataPacket;
+use crate::l2cap::L2capHeader;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ReassemblyError {
+    /// The PDU does not fit into the provided buffer.
+    BufferOverflow,
+    /// A continuation fragment arrived without a preceding start fragment.
+    UnexpectedContinuation,
+    /// The fragments carry more data than the L2CAP length declares.
+    LengthMismatch,
+}
+
+impl Display for ReassemblyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BufferOverflow => write!(f, "BufferOverflow"),
+            Self::UnexpectedContinuation => write!(f, "UnexpectedContinuation"),
+            Self::LengthMismatch => write!(f, "LengthMismatch"),
+        }
+    }
+}
+
+impl core::error::Error for ReassemblyError {}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.2
+/// Reassembles the L2CAP PDUs of one connection handle from ACL data fragments.
+///
+/// Packets of other handles are ignored, so the same packet stream can be fed to one reassembler
+/// per connection. A start fragment discards any incomplete PDU, and after an error the
+/// reassembler waits for the next start fragment.
+#[derive(Debug)]
+pub struct AclReassembler<'b> {
+    handle: u16,
+    buf: &'b mut [u8],
+    pos: usize,
+    in_progress: bool,
+}
+
+impl<'b> AclReassembler<'b> {
+    pub fn new(handle: u16, buf: &'b mut [u8]) -> Self {
+        Self {
+            handle,
+            buf,
+            pos: 0,
+            in_progress: false,
+        }
+    }
+
+    pub const fn handle(&self) -> u16 {
+        self.handle
+    }
+
+    /// Adds a fragment and returns the complete L2CAP PDU, including its basic header, once the
+    /// declared length is reached.
+    pub fn push(&mut self, packet: &HCIACLDataPacket) -> Result<Option<&[u8]>, ReassemblyError> {
+        if packet.handle != self.handle {
+            return Ok(None);
+        }
+
+        match packet.packet_boundary_flag {
+            HCIACLDataPacket::PB_CONTINUATION_FRAGMENT if !self.in_progress => {
+                return Err(ReassemblyError::UnexpectedContinuation);
+            }
+            HCIACLDataPacket::PB_CONTINUATION_FRAGMENT => {}
+            _ => {
+                self.pos = 0;
+                self.in_progress = true;
+            }
+        }
+
+        let end = self.pos + packet.data.len();
+        let Some(dest) = self.buf.get_mut(self.pos..end) else {
+            return Err(self.fail(ReassemblyError::BufferOverflow));
+        };
+        dest.copy_from_slice(packet.data);
+        self.pos = end;
+
+        let Some(pdu_len) = self.pdu_len() else {
+            return Ok(None);
+        };
+
+        if self.pos > pdu_len {
+            return Err(self.fail(ReassemblyError::LengthMismatch));
+        }
+        if pdu_len > self.buf.len() {
+            return Err(self.fail(ReassemblyError::BufferOverflow));
+        }
+        if self.pos < pdu_len {
+            return Ok(None);
+        }
+
+        self.in_progress = false;
+        self.pos = 0;
+        Ok(Some(&self.buf[..pdu_len]))
+    }
+
+    fn pdu_len(&self) -> Option<usize> {
+        let length = self.buf[..self.pos].first_chunk::<2>()?;
+
+        Some(u16::from_le_bytes(*length) as usize + L2capHeader::SIZE)
+    }
+
+    fn fail(&mut self, error: ReassemblyError) -> ReassemblyError {
+        self.in_progress = false;
+        self.pos = 0;
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HANDLE: u16 = 0x0040;
+
+    fn pdu() -> [u8; 40] {
+        let mut pdu = [0; 40];
+        pdu[..4].copy_from_slice(&[0x24, 0x00, 0x04, 0x00]); // length 36, ATT channel
+        for (i, byte) in pdu[4..].iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        pdu
+    }
+
+    fn fragment(handle: u16, packet_boundary_flag: u8, data: &[u8]) -> HCIACLDataPacket<'_> {
+        HCIACLDataPacket::new(handle, packet_boundary_flag, 0b00, data.len(), data)
+    }
+
+    #[test]
+    fn test_reassemble_fragments() {
+        let pdu = pdu();
+        let mut buf = [0; 64];
+        let mut reassembler = AclReassembler::new(HANDLE, &mut buf);
+
+        assert_eq!(
+            reassembler.push(&fragment(
+                HANDLE,
+                HCIACLDataPacket::PB_FIRST_FLUSHABLE,
+                &pdu[..27]
+            )),
+            Ok(None)
+        );
+        assert_eq!(
+            reassembler.push(&fragment(
+                HANDLE,
+                HCIACLDataPacket::PB_CONTINUATION_FRAGMENT,
+                &pdu[27..]
+            )),
+            Ok(Some(&pdu[..]))
+        );
+    }
+
+    #[test]
+    fn test_reassemble_interleaved_handles() {
+        let pdu = pdu();
+        let other = [0x01, 0x00, 0x05, 0x00, 0xFF];
+        let mut buf = [0; 64];
+        let mut reassembler = AclReassembler::new(HANDLE, &mut buf);
+
+        let first = fragment(HANDLE, HCIACLDataPacket::PB_FIRST_FLUSHABLE, &pdu[..27]);
+        let foreign = fragment(0x0041, HCIACLDataPacket::PB_FIRST_FLUSHABLE, &other);
+        let last = fragment(
+            HANDLE,
+            HCIACLDataPacket::PB_CONTINUATION_FRAGMENT,
+            &pdu[27..],
+        );
+
+        assert_eq!(reassembler.push(&first), Ok(None));
+        assert_eq!(reassembler.push(&foreign), Ok(None));
+        assert_eq!(reassembler.push(&last), Ok(Some(&pdu[..])));
+    }
+
+    #[test]
+    fn test_reassemble_buffer_overflow() {
+        let pdu = pdu();
+        let mut buf = [0; 32];
+        let mut reassembler = AclReassembler::new(HANDLE, &mut buf);
+
+        assert_eq!(
+            reassembler.push(&fragment(
+                HANDLE,
+                HCIACLDataPacket::PB_FIRST_FLUSHABLE,
+                &pdu[..27]
+            )),
+            Err(ReassemblyError::BufferOverflow)
+        );
+        assert_eq!(
+            reassembler.push(&fragment(
+                HANDLE,
+                HCIACLDataPacket::PB_CONTINUATION_FRAGMENT,
+                &pdu[27..]
+            )),
+            Err(ReassemblyError::UnexpectedContinuation)
+        );
+    }
+
+    #[test]
+    fn test_reassemble_length_mismatch() {
+        let mut buf = [0; 64];
+        let mut reassembler = AclReassembler::new(HANDLE, &mut buf);
+
+        // Declares a 1 byte payload but carries 2
+        assert_eq!(
+            reassembler.push(&fragment(
+                HANDLE,
+                HCIACLDataPacket::PB_FIRST_FLUSHABLE,
+                &[0x01, 0x00, 0x04, 0x00, 0xAA, 0xBB]
+            )),
+            Err(ReassemblyError::LengthMismatch)
+        );
+    }
+
+    #[test]
+    fn test_start_fragment_discards_incomplete_pdu() {
+        let pdu = pdu();
+        let single = [0x01, 0x00, 0x04, 0x00, 0xAA];
+        let mut buf = [0; 64];
+        let mut reassembler = AclReassembler::new(HANDLE, &mut buf);
+
+        assert_eq!(
+            reassembler.push(&fragment(
+                HANDLE,
+                HCIACLDataPacket::PB_FIRST_FLUSHABLE,
+                &pdu[..27]
+            )),
+            Ok(None)
+        );
+        assert_eq!(
+            reassembler.push(&fragment(
+                HANDLE,
+                HCIACLDataPacket::PB_FIRST_FLUSHABLE,
+                &single
+            )),
+            Ok(Some(&single[..]))
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use core::fmt::Display;
+
+use utils::reader::Reader;
+
+/// Bluetooth Core spec 6.0 | [Vol 2] Part B, Section 1.2
+///
+/// A 48-bit device address, stored least significant octet first as it is transmitted.
+///
+/// The conventional `AA:BB:CC:DD:EE:FF` notation starts with the most significant octet, so
+/// [`Display`] prints the bytes in reverse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BdAddr([u8; 6]);
+
+impl BdAddr {
+    pub const fn from_le_bytes(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+
+    pub const fn to_le_bytes(self) -> [u8; 6] {
+        self.0
+    }
+
+    pub fn read(reader: &mut Reader) -> Option<Self> {
+        reader.read_array().map(Self::from_le_bytes)
+    }
+
+    // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.10
+    // The address type byte that accompanies an address in events: 0x00 public, 0x01 random,
+    // 0x02 public identity and 0x03 random (static) identity, the last two after resolving an RPA.
+    pub const fn is_public(address_type: u8) -> bool {
+        matches!(address_type, 0x00 | 0x02)
+    }
+
+    pub const fn is_random(address_type: u8) -> bool {
+        matches!(address_type, 0x01 | 0x03)
+    }
+}
+
+impl Display for BdAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [b0, b1, b2, b3, b4, b5] = self.0;
+        write!(f, "{b5:02X}:{b4:02X}:{b3:02X}:{b2:02X}:{b1:02X}:{b0:02X}")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for BdAddr {
+    fn format(&self, f: defmt::Formatter) {
+        let [b0, b1, b2, b3, b4, b5] = self.0;
+        defmt::write!(
+            f,
+            "{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}",
+            b5,
+            b4,
+            b3,
+            b2,
+            b1,
+            b0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn test_display_reverses_bytes() {
+        let addr = BdAddr::from_le_bytes([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+
+        assert_eq!(addr.to_string(), "06:05:04:03:02:01");
+    }
+
+    #[test]
+    fn test_le_bytes_round_trip() {
+        let bytes = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+
+        assert_eq!(BdAddr::from_le_bytes(bytes).to_le_bytes(), bytes);
+        assert_eq!(
+            BdAddr::from_le_bytes(bytes).to_string(),
+            "FF:EE:DD:CC:BB:AA"
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let buf = [0x22, 0x11, 0x00, 0xEE, 0xFF, 0xC0, 0x01];
+        let mut reader = Reader::new(&buf);
+
+        let addr = BdAddr::read(&mut reader).unwrap();
+
+        assert_eq!(addr.to_string(), "C0:FF:EE:00:11:22");
+        assert_eq!(reader.remaining(), 1);
+        assert_eq!(BdAddr::read(&mut reader), None);
+    }
+
+    #[test]
+    fn test_address_type() {
+        assert!(BdAddr::is_public(0x00));
+        assert!(BdAddr::is_public(0x02));
+        assert!(BdAddr::is_random(0x01));
+        assert!(BdAddr::is_random(0x03));
+        assert!(!BdAddr::is_public(0x01));
+        assert!(!BdAddr::is_random(0x00));
+        assert!(!BdAddr::is_public(0xFF) && !BdAddr::is_random(0xFF));
+    }
+}
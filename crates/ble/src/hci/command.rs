@@ -1,108 +1,152 @@
 use core::result::Result;
 use macros::Size;
-use utils::writer::{WriteError, Writer};
+use utils::writer::{ByteSink, WriteError, Writer};
 
-use super::{HCIPacket, gap::AdvertisingData};
+use super::{HCICommandPacket, HCIPacket, Opcode, address::BdAddr, gap::AdvertisingData};
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.1 | page 1909
 // Link Control commands
 #[allow(unused)]
-const OGF_LINK_CONTROL_COMMAND: u16 = 0x01;
+const OGF_LINK_CONTROL_COMMAND: u8 = 0x01;
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.2 | page 2010
 // Link Policy commands
 #[allow(unused)]
-const OGF_LINK_POLICY_COMMAND: u16 = 0x02;
+const OGF_LINK_POLICY_COMMAND: u8 = 0x02;
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.3 | page 2037
 // Controller & Baseband commands
-const OGF_CONTROL_AND_BASEBAND_COMMAND: u16 = 0x03;
+const OGF_CONTROL_AND_BASEBAND_COMMAND: u8 = 0x03;
 
+const OCF_SET_EVENT_MASK: u16 = 0x1; // 7.3.1
 const OCF_RESET: u16 = 0x3; // 7.3.2
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.4 | page 2190
 // Informational parameters
-#[allow(unused)]
-const OGF_INFORMATIONAL_PARAMETERS_COMMAND: u16 = 0x04;
+const OGF_INFORMATIONAL_PARAMETERS_COMMAND: u8 = 0x04;
 
 #[allow(unused)]
 const OCF_READ_LOCAL_SUPPORTED_COMMANDS: u16 = 0x2; // 7.4.2
+const OCF_READ_BD_ADDR: u16 = 0x9; // 7.4.6
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.5 | page 2220
 // Status parameters
 #[allow(unused)]
-const OGF_STATUS_PARAMETERS_COMMAND: u16 = 0x05;
+const OGF_STATUS_PARAMETERS_COMMAND: u8 = 0x05;
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.6 | page 2237
 // Testing commands
 #[allow(unused)]
-const OGF_TESTING_COMMAND: u16 = 0x06;
+const OGF_TESTING_COMMAND: u8 = 0x06;
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.8 | page 2483
 // LE Controller commands
-const OGF_LE_CONTROLLER_COMMAND: u16 = 0x08;
+const OGF_LE_CONTROLLER_COMMAND: u8 = 0x08;
 
+const OCF_SET_LE_EVENT_MASK: u16 = 0x01; // 7.8.1
 const OCF_SET_ADVERTISING_PARAMETERS: u16 = 0x06; // 7.8.5
 const OCF_SET_ADVERTISING_DATA: u16 = 0x08; // 7.8.7
 const OCF_SET_RESPONSE_DATA: u16 = 0x9; // 7.7.8
 const OCF_SET_ADVERTISING_ENABLE: u16 = 0x0A; // 7.8.9
 const OCF_SET_SCAN_PARAMETERS: u16 = 0x0B; // 7.8.10
 const OCF_SET_SCAN_ENABLE: u16 = 0x0C; // 7.8.11
+const OCF_CREATE_CONNECTION: u16 = 0x0D; // 7.8.12
+const OCF_CREATE_CONNECTION_CANCEL: u16 = 0x0E; // 7.8.13
+const OCF_ADD_DEVICE_TO_RESOLVING_LIST: u16 = 0x27; // 7.8.38
+const OCF_CLEAR_RESOLVING_LIST: u16 = 0x29; // 7.8.40
+const OCF_SET_ADDRESS_RESOLUTION_ENABLE: u16 = 0x2D; // 7.8.44
+const OCF_SET_DEFAULT_PHY: u16 = 0x31; // 7.8.48
+const OCF_SET_ADVERTISING_SET_RANDOM_ADDRESS: u16 = 0x35; // 7.8.52
 
-// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.1 | page 1872
-// [...] Each command is assigned a 2 byte Opcode used to uniquely identify different types of commands.
-// The Opcode parameter is divided into two fields, called the Opcode Group Field (OGF) and Opcode Command Field (OCF).
-// The OGF occupies the upper 6 bits of the Opcode, while the OCF occupies the remaining 10 bits. [...]
-
-const fn opcode(ocf: u16, ogf: u16) -> u16 {
-    ocf | (ogf << 10)
-}
+const MAX_ADVERTISING_DATA_SIZE: usize = 31; // 7.8.7
 
 #[derive(Debug)]
 pub enum HCICommand<'p> {
+    SetEventMask(EventMask),                                   // 7.3.1
     Reset,                                                     // 7.3.2
+    ReadBdAddr,                                                // 7.4.6
+    SetLEEventMask(LEEventMask),                               // 7.8.1
     SetAdvertisingParameters(SetAdvertisingParametersCommand), // 7.8.5
     SetAdvertisingData { data: &'p [AdvertisingData<'p>] },    // 7.8.7
+    SetRawAdvertisingData { data: &'p [u8] },                  // 7.8.7
     SetScanResponseData { data: &'p [AdvertisingData<'p>] },   // 7.8.8
     SetAdvertisingEnable { enable: u8 },                       // 7.8.9
     SetScanParameters(SetScanParametersCommand),               // 7.8.10
     ScanEnable(ScanEnableCommand),                             // 7.8.11
+    CreateConnection(CreateConnectionCommand),                 // 7.8.12
+    CreateConnectionCancel,                                    // 7.8.13
+    AddDeviceToResolvingList(AddDeviceToResolvingListCommand), // 7.8.38
+    ClearResolvingList,                                        // 7.8.40
+    SetAddressResolutionEnable { enable: u8 },                 // 7.8.44
+    SetDefaultPhy(SetDefaultPhyCommand),                       // 7.8.48
+    SetAdvertisingSetRandomAddress(SetAdvertisingSetRandomAddressCommand), // 7.8.52
 }
 
 impl HCICommand<'_> {
+    pub const fn opcode(&self) -> Opcode {
+        match self {
+            Self::SetEventMask(_) => {
+                Opcode::new(OGF_CONTROL_AND_BASEBAND_COMMAND, OCF_SET_EVENT_MASK)
+            }
+            Self::Reset => Opcode::new(OGF_CONTROL_AND_BASEBAND_COMMAND, OCF_RESET),
+            Self::ReadBdAddr => Opcode::new(OGF_INFORMATIONAL_PARAMETERS_COMMAND, OCF_READ_BD_ADDR),
+            Self::SetLEEventMask(_) => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_LE_EVENT_MASK)
+            }
+            Self::SetAdvertisingParameters(_) => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_ADVERTISING_PARAMETERS)
+            }
+            Self::SetAdvertisingData { .. } | Self::SetRawAdvertisingData { .. } => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_ADVERTISING_DATA)
+            }
+            Self::SetScanResponseData { .. } => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_RESPONSE_DATA)
+            }
+            Self::SetAdvertisingEnable { .. } => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_ADVERTISING_ENABLE)
+            }
+            Self::SetScanParameters(_) => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_SCAN_PARAMETERS)
+            }
+            Self::ScanEnable(_) => Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_SCAN_ENABLE),
+            Self::CreateConnection(_) => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_CREATE_CONNECTION)
+            }
+            Self::CreateConnectionCancel => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_CREATE_CONNECTION_CANCEL)
+            }
+            Self::AddDeviceToResolvingList(_) => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_ADD_DEVICE_TO_RESOLVING_LIST)
+            }
+            Self::ClearResolvingList => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_CLEAR_RESOLVING_LIST)
+            }
+            Self::SetAddressResolutionEnable { .. } => {
+                Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_ADDRESS_RESOLUTION_ENABLE)
+            }
+            Self::SetDefaultPhy(_) => Opcode::new(OGF_LE_CONTROLLER_COMMAND, OCF_SET_DEFAULT_PHY),
+            Self::SetAdvertisingSetRandomAddress(_) => Opcode::new(
+                OGF_LE_CONTROLLER_COMMAND,
+                OCF_SET_ADVERTISING_SET_RANDOM_ADDRESS,
+            ),
+        }
+    }
+
     pub fn write_into(&self, buf: &mut [u8]) -> Result<usize, WriteError> {
-        let mut writer = Writer::new(buf);
-        writer.write_u8(HCIPacket::COMMAND_PACKET_TYPE)?;
+        let mut writer = CommandWriter::new(buf, self.opcode())?;
 
         match self {
-            Self::Reset => {
-                writer.write_u16(opcode(OCF_RESET, OGF_CONTROL_AND_BASEBAND_COMMAND))?;
-                writer.write_u8(0)?;
-            }
-            Self::SetAdvertisingParameters(command) => {
-                writer.write_u16(opcode(
-                    OCF_SET_ADVERTISING_PARAMETERS,
-                    OGF_LE_CONTROLLER_COMMAND,
-                ))?;
-                writer.write_u8(command.size() as u8)?;
-                writer.write_u16(command.interval_min)?;
-                writer.write_u16(command.interval_max)?;
-                writer.write_u8(command.advertising_type)?;
-                writer.write_u8(command.own_address_type)?;
-                writer.write_u8(command.peer_address_type)?;
-                writer.write_u8_slice(&command.peer_address)?;
-                writer.write_u8(command.advertising_channel_map)?;
-                writer.write_u8(command.advertising_filter_policy)?;
-            }
+            Self::SetEventMask(mask) => writer.write_u64(mask.bits())?,
+            Self::Reset => {}
+            Self::ReadBdAddr => {}
+            Self::SetLEEventMask(mask) => writer.write_u64(mask.bits())?,
+            Self::SetAdvertisingParameters(command) => command.write(&mut writer)?,
             Self::ScanEnable(command) => {
-                writer.write_u16(opcode(OCF_SET_SCAN_ENABLE, OGF_LE_CONTROLLER_COMMAND))?;
-                writer.write_u8(command.size() as u8)?;
                 writer.write_u8(command.scan_enable)?;
                 writer.write_u8(command.filter_duplicates)?;
             }
             Self::SetScanParameters(command) => {
-                writer.write_u16(opcode(OCF_SET_SCAN_PARAMETERS, OGF_LE_CONTROLLER_COMMAND))?;
-                writer.write_u8(command.size() as u8)?;
+                command.validate()?;
                 writer.write_u8(command.scan_type)?;
                 writer.write_u16(command.scan_interval)?;
                 writer.write_u16(command.scan_window)?;
@@ -110,8 +154,6 @@ impl HCICommand<'_> {
                 writer.write_u8(command.scanning_filter_policy)?;
             }
             Self::SetAdvertisingData { data } => {
-                writer.write_u16(opcode(OCF_SET_ADVERTISING_DATA, OGF_LE_CONTROLLER_COMMAND))?;
-                writer.write_u8(32)?;
                 let mut buf = [0; 31];
 
                 let mut offset = 0;
@@ -124,9 +166,17 @@ impl HCICommand<'_> {
                 writer.write_u8(offset as u8)?;
                 writer.write_u8_slice(&buf)?;
             }
+            Self::SetRawAdvertisingData { data } => {
+                if data.len() > MAX_ADVERTISING_DATA_SIZE {
+                    return Err(WriteError::InvalidFormat);
+                }
+
+                // The Advertising_Data parameter is always 31 octets, only the first len are significant
+                writer.write_u8(data.len() as u8)?;
+                writer.write_u8_slice(data)?;
+                writer.write_u8_slice(&[0; MAX_ADVERTISING_DATA_SIZE][data.len()..])?;
+            }
             Self::SetScanResponseData { data } => {
-                writer.write_u16(opcode(OCF_SET_RESPONSE_DATA, OGF_LE_CONTROLLER_COMMAND))?;
-                writer.write_u8(32)?;
                 let mut buf = [0; 31];
 
                 let mut offset = 0;
@@ -140,30 +190,269 @@ impl HCICommand<'_> {
                 writer.write_u8_slice(&buf)?;
             }
             Self::SetAdvertisingEnable { enable } => {
-                writer.write_u16(opcode(
-                    OCF_SET_ADVERTISING_ENABLE,
-                    OGF_LE_CONTROLLER_COMMAND,
-                ))?;
-                writer.write_u8(size_of::<u8>() as u8)?;
                 writer.write_u8(*enable)?;
             }
+            Self::CreateConnection(command) => command.write(&mut writer)?,
+            Self::CreateConnectionCancel => {}
+            Self::AddDeviceToResolvingList(command) => {
+                writer.write_u8(command.peer_identity_address_type)?;
+                writer.write_u8_slice(&command.peer_identity_address.to_le_bytes())?;
+                writer.write_u8_slice(&command.peer_irk)?;
+                writer.write_u8_slice(&command.local_irk)?;
+            }
+            Self::ClearResolvingList => {}
+            Self::SetAddressResolutionEnable { enable } => {
+                writer.write_u8(*enable)?;
+            }
+            Self::SetDefaultPhy(command) => {
+                command.validate()?;
+                writer.write_u8(command.all_phys)?;
+                writer.write_u8(command.tx_phys.bits())?;
+                writer.write_u8(command.rx_phys.bits())?;
+            }
+            Self::SetAdvertisingSetRandomAddress(command) => {
+                writer.write_u8(command.advertising_handle)?;
+                writer.write_u8_slice(&command.random_address.to_le_bytes())?;
+            }
         }
 
-        Ok(writer.pos)
+        Ok(writer.finish())
+    }
+}
+
+/// Writes an HCI command packet into a buffer, filling in the parameter length of the header as
+/// parameters are written.
+///
+/// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.1 | page 1872
+/// Writing more than 255 parameter bytes fails with [`WriteError::InvalidFormat`].
+#[derive(Debug)]
+pub struct CommandWriter<'p> {
+    len: &'p mut u8,
+    parameters: Writer<'p>,
+}
+
+impl<'p> CommandWriter<'p> {
+    const HEADER_SIZE: usize = size_of::<u8>() + HCICommandPacket::HEADER_SIZE;
+
+    pub fn new(buf: &'p mut [u8], opcode: Opcode) -> Result<Self, WriteError> {
+        if buf.len() < Self::HEADER_SIZE {
+            return Err(WriteError::BufferOverflow);
+        }
+
+        let (header, parameters) = buf.split_at_mut(Self::HEADER_SIZE);
+        let (header, len) = header.split_at_mut(Self::HEADER_SIZE - size_of::<u8>());
+
+        header[0] = HCIPacket::COMMAND_PACKET_TYPE;
+        header[1..].copy_from_slice(&opcode.into_u16().to_le_bytes());
+        len[0] = 0;
+
+        Ok(Self {
+            len: &mut len[0],
+            parameters: Writer::new(parameters),
+        })
+    }
+
+    pub fn write_u8_slice(&mut self, slice: &[u8]) -> Result<(), WriteError> {
+        if self.parameters.pos + slice.len() > HCICommandPacket::MAX_PARAMETERS_SIZE {
+            return Err(WriteError::InvalidFormat);
+        }
+
+        self.parameters.write_u8_slice(slice)?;
+        *self.len = self.parameters.pos as u8;
+
+        Ok(())
+    }
+
+    /// Returns the length of the whole packet.
+    pub fn finish(self) -> usize {
+        Self::HEADER_SIZE + self.parameters.pos
+    }
+}
+
+impl ByteSink for CommandWriter<'_> {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WriteError> {
+        self.write_u8_slice(bytes)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.3.1
+// Bit field of the events the Controller may send. Command Complete and Command Status are always
+// sent and have no bit, LE subevents are enabled separately with the LE event mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventMask(u64);
+
+impl EventMask {
+    pub const DISCONNECTION_COMPLETE: u64 = 1 << 4;
+    pub const ENCRYPTION_CHANGE: u64 = 1 << 7;
+    pub const READ_REMOTE_VERSION_INFORMATION_COMPLETE: u64 = 1 << 11;
+    pub const HARDWARE_ERROR: u64 = 1 << 15;
+    pub const DATA_BUFFER_OVERFLOW: u64 = 1 << 25;
+    pub const LE_META_EVENT: u64 = 1 << 61;
+
+    // The mask the Controller uses after a reset
+    pub const DEFAULT: u64 = 0x0000_1FFF_FFFF_FFFF;
+
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for EventMask {
+    fn default() -> Self {
+        Self(Self::DEFAULT)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.8.1
+// Bit field of the LE subevents the Controller may send, bit n - 1 enables subevent code n. They
+// are only sent if LE Meta events are enabled in the EventMask as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LEEventMask(u64);
+
+impl LEEventMask {
+    pub const CONNECTION_COMPLETE: u64 = 1 << 0;
+    pub const ADVERTISING_REPORT: u64 = 1 << 1;
+    pub const CONNECTION_UPDATE_COMPLETE: u64 = 1 << 2;
+    pub const READ_REMOTE_FEATURES_COMPLETE: u64 = 1 << 3;
+    pub const LONG_TERM_KEY_REQUEST: u64 = 1 << 4;
+    pub const REMOTE_CONNECTION_PARAMETER_REQUEST: u64 = 1 << 5;
+    pub const ENHANCED_CONNECTION_COMPLETE: u64 = 1 << 9;
+    pub const DIRECTED_ADVERTISING_REPORT: u64 = 1 << 10;
+    pub const PHY_UPDATE_COMPLETE: u64 = 1 << 11;
+    pub const EXTENDED_ADVERTISING_REPORT: u64 = 1 << 12;
+    pub const SCAN_TIMEOUT: u64 = 1 << 16;
+    pub const ADVERTISING_SET_TERMINATED: u64 = 1 << 17;
+    pub const CHANNEL_SELECTION_ALGORITHM: u64 = 1 << 19;
+
+    // The mask the Controller uses after a reset
+    pub const DEFAULT: u64 = 0x0000_0000_0000_001F;
+
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for LEEventMask {
+    fn default() -> Self {
+        Self(Self::DEFAULT)
     }
 }
 
 // 7.8.5 LE Set Advertising Parameters command
-#[derive(Debug, Size)]
+// Every parameter starts at a default and can be changed with the setter of the same name. The
+// advertising interval is in units of 0.625 ms.
+#[derive(Debug, Clone, Size)]
 pub struct SetAdvertisingParametersCommand {
-    pub interval_min: u16,
-    pub interval_max: u16,
-    pub advertising_type: u8,
-    pub own_address_type: u8,
-    pub peer_address_type: u8,
-    pub peer_address: [u8; 6],
-    pub advertising_channel_map: u8,
-    pub advertising_filter_policy: u8,
+    interval_min: u16,
+    interval_max: u16,
+    advertising_type: u8,
+    own_address_type: u8,
+    peer_address_type: u8,
+    peer_address: BdAddr,
+    advertising_channel_map: u8,
+    advertising_filter_policy: u8,
+}
+
+impl SetAdvertisingParametersCommand {
+    pub const ADV_IND: u8 = 0x00;
+    pub const ADV_DIRECT_IND_HIGH_DUTY_CYCLE: u8 = 0x01;
+    pub const ADV_SCAN_IND: u8 = 0x02;
+    pub const ADV_NONCONN_IND: u8 = 0x03;
+    pub const ADV_DIRECT_IND_LOW_DUTY_CYCLE: u8 = 0x04;
+
+    pub const CHANNEL_37: u8 = 0b001;
+    pub const CHANNEL_38: u8 = 0b010;
+    pub const CHANNEL_39: u8 = 0b100;
+
+    const INTERVAL_RANGE: core::ops::RangeInclusive<u16> = 0x0020..=0x4000;
+
+    /// Connectable undirected advertising every 1.28 s on all three primary channels, accepting
+    /// scan and connection requests from any device.
+    pub const fn new() -> Self {
+        Self {
+            interval_min: 0x0800,
+            interval_max: 0x0800,
+            advertising_type: Self::ADV_IND,
+            own_address_type: 0x00,
+            peer_address_type: 0x00,
+            peer_address: BdAddr::from_le_bytes([0; 6]),
+            advertising_channel_map: Self::CHANNEL_37 | Self::CHANNEL_38 | Self::CHANNEL_39,
+            advertising_filter_policy: 0x00,
+        }
+    }
+
+    pub const fn interval_min(mut self, interval_min: u16) -> Self {
+        self.interval_min = interval_min;
+        self
+    }
+
+    pub const fn interval_max(mut self, interval_max: u16) -> Self {
+        self.interval_max = interval_max;
+        self
+    }
+
+    pub const fn advertising_type(mut self, advertising_type: u8) -> Self {
+        self.advertising_type = advertising_type;
+        self
+    }
+
+    pub const fn own_address_type(mut self, own_address_type: u8) -> Self {
+        self.own_address_type = own_address_type;
+        self
+    }
+
+    // Only used by directed advertising
+    pub const fn peer_address(mut self, peer_address_type: u8, peer_address: BdAddr) -> Self {
+        self.peer_address_type = peer_address_type;
+        self.peer_address = peer_address;
+        self
+    }
+
+    pub const fn advertising_channel_map(mut self, advertising_channel_map: u8) -> Self {
+        self.advertising_channel_map = advertising_channel_map;
+        self
+    }
+
+    pub const fn advertising_filter_policy(mut self, advertising_filter_policy: u8) -> Self {
+        self.advertising_filter_policy = advertising_filter_policy;
+        self
+    }
+
+    /// Writes the command parameters, failing with [`WriteError::InvalidFormat`] if an interval is
+    /// outside 0x0020 to 0x4000 or the minimum is larger than the maximum. High duty cycle directed
+    /// advertising ignores the interval, so it is not checked for that type.
+    pub fn write(&self, writer: &mut impl ByteSink) -> Result<(), WriteError> {
+        let invalid_interval = !Self::INTERVAL_RANGE.contains(&self.interval_min)
+            || !Self::INTERVAL_RANGE.contains(&self.interval_max)
+            || self.interval_min > self.interval_max;
+
+        if invalid_interval && self.advertising_type != Self::ADV_DIRECT_IND_HIGH_DUTY_CYCLE {
+            return Err(WriteError::InvalidFormat);
+        }
+
+        writer.write_u16(self.interval_min)?;
+        writer.write_u16(self.interval_max)?;
+        writer.write_u8(self.advertising_type)?;
+        writer.write_u8(self.own_address_type)?;
+        writer.write_u8(self.peer_address_type)?;
+        writer.write_bytes(&self.peer_address.to_le_bytes())?;
+        writer.write_u8(self.advertising_channel_map)?;
+        writer.write_u8(self.advertising_filter_policy)
+    }
+}
+
+impl Default for SetAdvertisingParametersCommand {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // 7.8.10 LE Set Scan Paramaters command
@@ -176,9 +465,364 @@ pub struct SetScanParametersCommand {
     pub scanning_filter_policy: u8,
 }
 
+impl SetScanParametersCommand {
+    // The scan window must not be longer than the scan interval.
+    fn validate(&self) -> Result<(), WriteError> {
+        match self.scan_window > self.scan_interval {
+            true => Err(WriteError::InvalidFormat),
+            false => Ok(()),
+        }
+    }
+}
+
 // 7.8.11 LE Set Scan Enable command
 #[derive(Debug, Size)]
 pub struct ScanEnableCommand {
     pub scan_enable: u8,
     pub filter_duplicates: u8,
 }
+
+// 7.8.12 LE Create Connection command
+// Built from the peer to connect to, every other parameter starts at a default and can be
+// changed with the setter of the same name. Intervals, latency and timeouts are in the units
+// the spec defines for them, 0.625 ms for scanning, 1.25 ms for the connection interval and
+// 10 ms for the supervision timeout.
+#[derive(Debug, Clone, Size)]
+pub struct CreateConnectionCommand {
+    scan_interval: u16,
+    scan_window: u16,
+    initiator_filter_policy: u8,
+    peer_address_type: u8,
+    peer_address: BdAddr,
+    own_address_type: u8,
+    connection_interval_min: u16,
+    connection_interval_max: u16,
+    max_latency: u16,
+    supervision_timeout: u16,
+    min_ce_length: u16,
+    max_ce_length: u16,
+}
+
+impl CreateConnectionCommand {
+    /// Scans for 30 ms every 60 ms and asks for a 30 ms to 50 ms connection interval without
+    /// latency and a 5 s supervision timeout.
+    pub const fn new(peer_address_type: u8, peer_address: BdAddr) -> Self {
+        Self {
+            scan_interval: 0x0060,
+            scan_window: 0x0030,
+            initiator_filter_policy: 0x00,
+            peer_address_type,
+            peer_address,
+            own_address_type: 0x00,
+            connection_interval_min: 0x0018,
+            connection_interval_max: 0x0028,
+            max_latency: 0x0000,
+            supervision_timeout: 0x01F4,
+            min_ce_length: 0x0000,
+            max_ce_length: 0x0000,
+        }
+    }
+
+    pub const fn scan_interval(mut self, scan_interval: u16) -> Self {
+        self.scan_interval = scan_interval;
+        self
+    }
+
+    pub const fn scan_window(mut self, scan_window: u16) -> Self {
+        self.scan_window = scan_window;
+        self
+    }
+
+    // 0x00 connects to the peer address, 0x01 to any device on the Filter Accept List
+    pub const fn initiator_filter_policy(mut self, initiator_filter_policy: u8) -> Self {
+        self.initiator_filter_policy = initiator_filter_policy;
+        self
+    }
+
+    pub const fn own_address_type(mut self, own_address_type: u8) -> Self {
+        self.own_address_type = own_address_type;
+        self
+    }
+
+    pub const fn connection_interval_min(mut self, connection_interval_min: u16) -> Self {
+        self.connection_interval_min = connection_interval_min;
+        self
+    }
+
+    pub const fn connection_interval_max(mut self, connection_interval_max: u16) -> Self {
+        self.connection_interval_max = connection_interval_max;
+        self
+    }
+
+    pub const fn max_latency(mut self, max_latency: u16) -> Self {
+        self.max_latency = max_latency;
+        self
+    }
+
+    pub const fn supervision_timeout(mut self, supervision_timeout: u16) -> Self {
+        self.supervision_timeout = supervision_timeout;
+        self
+    }
+
+    pub const fn min_ce_length(mut self, min_ce_length: u16) -> Self {
+        self.min_ce_length = min_ce_length;
+        self
+    }
+
+    pub const fn max_ce_length(mut self, max_ce_length: u16) -> Self {
+        self.max_ce_length = max_ce_length;
+        self
+    }
+
+    /// Writes the command parameters, failing with [`WriteError::InvalidFormat`] if the scan
+    /// window is longer than the scan interval or the minimum connection interval is larger than
+    /// the maximum.
+    pub fn write(&self, writer: &mut impl ByteSink) -> Result<(), WriteError> {
+        if self.scan_window > self.scan_interval
+            || self.connection_interval_min > self.connection_interval_max
+        {
+            return Err(WriteError::InvalidFormat);
+        }
+
+        writer.write_u16(self.scan_interval)?;
+        writer.write_u16(self.scan_window)?;
+        writer.write_u8(self.initiator_filter_policy)?;
+        writer.write_u8(self.peer_address_type)?;
+        writer.write_bytes(&self.peer_address.to_le_bytes())?;
+        writer.write_u8(self.own_address_type)?;
+        writer.write_u16(self.connection_interval_min)?;
+        writer.write_u16(self.connection_interval_max)?;
+        writer.write_u16(self.max_latency)?;
+        writer.write_u16(self.supervision_timeout)?;
+        writer.write_u16(self.min_ce_length)?;
+        writer.write_u16(self.max_ce_length)
+    }
+}
+
+// 7.8.38 LE Add Device To Resolving List command
+// The IRKs are transmitted least significant octet first, like every other multi-octet parameter.
+#[derive(Debug, Size)]
+pub struct AddDeviceToResolvingListCommand {
+    pub peer_identity_address_type: u8,
+    pub peer_identity_address: BdAddr,
+    pub peer_irk: [u8; 16],
+    pub local_irk: [u8; 16],
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.8.48
+// Bit field of the PHYs the Host prefers, shared by the TX_PHYs and RX_PHYs parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PhyMask(u8);
+
+impl PhyMask {
+    pub const LE_1M: u8 = 0b0000_0001;
+    pub const LE_2M: u8 = 0b0000_0010;
+    pub const LE_CODED: u8 = 0b0000_0100;
+
+    const RESERVED: u8 = !(Self::LE_1M | Self::LE_2M | Self::LE_CODED);
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+// 7.8.48 LE Set Default PHY command
+#[derive(Debug, Size)]
+pub struct SetDefaultPhyCommand {
+    pub all_phys: u8,
+    pub tx_phys: PhyMask,
+    pub rx_phys: PhyMask,
+}
+
+impl SetDefaultPhyCommand {
+    pub const NO_TX_PREFERENCE: u8 = 0b0000_0001;
+    pub const NO_RX_PREFERENCE: u8 = 0b0000_0010;
+
+    // A direction without "no preference" set needs at least one PHY, and reserved bits must be 0.
+    fn validate(&self) -> Result<(), WriteError> {
+        let reserved = self.all_phys & !(Self::NO_TX_PREFERENCE | Self::NO_RX_PREFERENCE) != 0
+            || self.tx_phys.bits() & PhyMask::RESERVED != 0
+            || self.rx_phys.bits() & PhyMask::RESERVED != 0;
+        let missing_tx = self.all_phys & Self::NO_TX_PREFERENCE == 0 && self.tx_phys.is_empty();
+        let missing_rx = self.all_phys & Self::NO_RX_PREFERENCE == 0 && self.rx_phys.is_empty();
+
+        match reserved || missing_tx || missing_rx {
+            true => Err(WriteError::InvalidFormat),
+            false => Ok(()),
+        }
+    }
+}
+
+// 7.8.52 LE Set Advertising Set Random Address command
+#[derive(Debug, Size)]
+pub struct SetAdvertisingSetRandomAddressCommand {
+    pub advertising_handle: u8,
+    pub random_address: BdAddr,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_writer_max_parameters() {
+        let mut buf = [0; 300];
+        let mut writer = CommandWriter::new(&mut buf, Opcode::from_u16(0x0000)).unwrap();
+
+        writer.write_u8_slice(&[0xAA; 255]).unwrap();
+        assert!(matches!(
+            writer.write_u8(0xAA),
+            Err(WriteError::InvalidFormat)
+        ));
+        assert_eq!(writer.finish(), 259);
+        assert_eq!(buf[3], 255);
+
+        let mut buf = [0; 300];
+        let mut writer = CommandWriter::new(&mut buf, Opcode::from_u16(0x0000)).unwrap();
+
+        assert!(matches!(
+            writer.write_u8_slice(&[0xAA; 256]),
+            Err(WriteError::InvalidFormat)
+        ));
+        assert_eq!(writer.finish(), 4);
+    }
+
+    #[test]
+    fn test_command_writer_header() {
+        let mut buf = [0; 8];
+        let mut writer = CommandWriter::new(&mut buf, Opcode::from_u16(0x200C)).unwrap();
+
+        writer.write_u8(0x01).unwrap();
+        writer.write_u8(0x00).unwrap();
+        let len = writer.finish();
+
+        assert_eq!(&buf[..len], &[0x01, 0x0C, 0x20, 0x02, 0x01, 0x00]);
+        assert!(CommandWriter::new(&mut [0; 3], Opcode::from_u16(0x200C)).is_err());
+    }
+
+    #[test]
+    fn test_create_connection() {
+        let peer = BdAddr::from_le_bytes([0x13, 0x71, 0xDA, 0x7D, 0x1A, 0x00]);
+        let command = CreateConnectionCommand::new(0x00, peer)
+            .scan_window(0x0060)
+            .own_address_type(0x01)
+            .supervision_timeout(0x002A);
+        let mut buf = [0; 32];
+
+        let len = HCICommand::CreateConnection(command.clone())
+            .write_into(&mut buf)
+            .unwrap();
+
+        assert_eq!(len, 4 + command.size());
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, // HCI command packet
+                0x0D, 0x20, // OCF 0x000D, OGF 0x08
+                0x19, // parameter length
+                0x60, 0x00, // scan interval 60 ms
+                0x60, 0x00, // scan window 60 ms
+                0x00, // connect to the peer address
+                0x00, // public peer address
+                0x13, 0x71, 0xDA, 0x7D, 0x1A, 0x00, // peer address, LSB first
+                0x01, // random own address
+                0x18, 0x00, // connection interval min 30 ms
+                0x28, 0x00, // connection interval max 50 ms
+                0x00, 0x00, // max latency
+                0x2A, 0x00, // supervision timeout 420 ms
+                0x00, 0x00, // min CE length
+                0x00, 0x00, // max CE length
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_connection_validation() {
+        let command = CreateConnectionCommand::new(0x00, BdAddr::default());
+        let mut buf = [0; 32];
+
+        assert!(
+            HCICommand::CreateConnection(
+                command
+                    .clone()
+                    .connection_interval_min(0x0028)
+                    .connection_interval_max(0x0028)
+            )
+            .write_into(&mut buf)
+            .is_ok()
+        );
+        assert!(matches!(
+            HCICommand::CreateConnection(command.clone().connection_interval_min(0x0029))
+                .write_into(&mut buf),
+            Err(WriteError::InvalidFormat)
+        ));
+        assert!(matches!(
+            HCICommand::CreateConnection(command.scan_window(0x0061)).write_into(&mut buf),
+            Err(WriteError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn test_set_advertising_parameters() {
+        let command = SetAdvertisingParametersCommand::new()
+            .interval_min(0x00A0)
+            .interval_max(0x00F0)
+            .advertising_type(SetAdvertisingParametersCommand::ADV_SCAN_IND)
+            .own_address_type(0x01);
+        let mut buf = [0; 24];
+
+        let len = HCICommand::SetAdvertisingParameters(command.clone())
+            .write_into(&mut buf)
+            .unwrap();
+
+        assert_eq!(len, 4 + command.size());
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, // HCI command packet
+                0x06, 0x20, // OCF 0x0006, OGF 0x08
+                0x0F, // parameter length
+                0xA0, 0x00, // advertising interval min 100 ms
+                0xF0, 0x00, // advertising interval max 150 ms
+                0x02, // ADV_SCAN_IND
+                0x01, // random own address
+                0x00, // public peer address
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // peer address
+                0x07, // channels 37, 38 and 39
+                0x00, // accept all scan and connection requests
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_advertising_parameters_interval_range() {
+        let write = |command: SetAdvertisingParametersCommand| {
+            HCICommand::SetAdvertisingParameters(command).write_into(&mut [0; 24])
+        };
+        let command = SetAdvertisingParametersCommand::default();
+
+        assert!(write(command.clone().interval_min(0x0020).interval_max(0x4000)).is_ok());
+        assert!(write(command.clone().interval_min(0x001F)).is_err());
+        assert!(write(command.clone().interval_max(0x4001)).is_err());
+        assert!(write(command.clone().interval_min(0x0801)).is_err());
+        assert!(
+            write(
+                command
+                    .interval_min(0x0000)
+                    .interval_max(0x0000)
+                    .advertising_type(
+                        SetAdvertisingParametersCommand::ADV_DIRECT_IND_HIGH_DUTY_CYCLE
+                    )
+            )
+            .is_ok()
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use utils::reader::Reader;
+
+use super::{CommandCompleteEvent, ErrorCode, HCICommand, Opcode, address::BdAddr};
+
+const RESET: Opcode = HCICommand::Reset.opcode(); // 7.3.2
+const READ_BD_ADDR: Opcode = HCICommand::ReadBdAddr.opcode(); // 7.4.6
+const LE_READ_BUFFER_SIZE: Opcode = Opcode::new(0x08, 0x0002); // 7.8.2
+const LE_READ_LOCAL_SUPPORTED_FEATURES: Opcode = Opcode::new(0x08, 0x0003); // 7.8.3
+
+/// The return parameters of a Command Complete event, decoded according to its opcode.
+///
+/// Opcodes without a decoder, and return parameters too short for the command they belong to,
+/// are kept as [`CommandReturn::Raw`].
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CommandReturn<'p> {
+    Reset { status: u8 },                                             // 7.3.2
+    ReadBdAddr(ReadBdAddrReturn),                                     // 7.4.6
+    LEReadBufferSize(LEReadBufferSizeReturn),                         // 7.8.2
+    LEReadLocalSupportedFeatures(LEReadLocalSupportedFeaturesReturn), // 7.8.3
+    Raw(&'p [u8]),
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.4.6
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadBdAddrReturn {
+    pub status: u8,
+    pub bd_addr: BdAddr,
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.8.2
+// Both values are 0 if the Controller shares its ACL buffers between BR/EDR and LE, the sizes then
+// have to be read with HCI_Read_Buffer_Size
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LEReadBufferSizeReturn {
+    pub status: u8,
+    pub le_acl_data_packet_length: u16,
+    pub total_num_le_acl_data_packets: u8,
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.8.3
+// le_features is the bit mask from [Vol 6] Part B, Section 4.6, least significant octet first
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LEReadLocalSupportedFeaturesReturn {
+    pub status: u8,
+    pub le_features: [u8; 8],
+}
+
+impl ReadBdAddrReturn {
+    fn read(reader: &mut Reader) -> Option<Self> {
+        Some(Self {
+            status: reader.read_u8()?,
+            bd_addr: BdAddr::read(reader)?,
+        })
+    }
+
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+impl LEReadBufferSizeReturn {
+    fn read(reader: &mut Reader) -> Option<Self> {
+        Some(Self {
+            status: reader.read_u8()?,
+            le_acl_data_packet_length: reader.read_u16()?,
+            total_num_le_acl_data_packets: reader.read_u8()?,
+        })
+    }
+
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+impl LEReadLocalSupportedFeaturesReturn {
+    fn read(reader: &mut Reader) -> Option<Self> {
+        Some(Self {
+            status: reader.read_u8()?,
+            le_features: reader.read_array()?,
+        })
+    }
+
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+impl<'p> CommandCompleteEvent<'p> {
+    pub fn decode_return_parameters(&self) -> CommandReturn<'p> {
+        let mut reader = Reader::new(self.return_parameters);
+
+        let decoded = match self.command_opcode {
+            RESET => reader
+                .read_u8()
+                .map(|status| CommandReturn::Reset { status }),
+            READ_BD_ADDR => ReadBdAddrReturn::read(&mut reader).map(CommandReturn::ReadBdAddr),
+            LE_READ_BUFFER_SIZE => {
+                LEReadBufferSizeReturn::read(&mut reader).map(CommandReturn::LEReadBufferSize)
+            }
+            LE_READ_LOCAL_SUPPORTED_FEATURES => {
+                LEReadLocalSupportedFeaturesReturn::read(&mut reader)
+                    .map(CommandReturn::LEReadLocalSupportedFeatures)
+            }
+            _ => None,
+        };
+
+        decoded.unwrap_or(CommandReturn::Raw(self.return_parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::hci::{
+        HCIEvent,
+        fixtures::{
+            LE_READ_BUFFER_SIZE_COMMAND_COMPLETE, LE_SET_SCAN_PARAMETERS_COMMAND_COMPLETE,
+            READ_BD_ADDR_COMMAND_COMPLETE, RESET_COMMAND_COMPLETE, event_packet,
+        },
+    };
+    use std::string::ToString;
+
+    fn decode(fixture: &[u8]) -> CommandReturn<'_> {
+        let Ok(HCIEvent::CommandComplete(event)) = HCIEvent::from_packet(&event_packet(fixture))
+        else {
+            panic!("Unexpected event type");
+        };
+
+        event.decode_return_parameters()
+    }
+
+    #[test]
+    fn test_reset() {
+        assert_eq!(
+            decode(RESET_COMMAND_COMPLETE),
+            CommandReturn::Reset { status: 0x00 }
+        );
+    }
+
+    #[test]
+    fn test_read_bd_addr() {
+        let CommandReturn::ReadBdAddr(ret) = decode(READ_BD_ADDR_COMMAND_COMPLETE) else {
+            panic!("Unexpected return parameters");
+        };
+
+        assert_eq!(ret.status(), Ok(ErrorCode::Success));
+        assert_eq!(
+            ret.bd_addr,
+            BdAddr::from_le_bytes([0x66, 0x55, 0x44, 0x33, 0x22, 0x11])
+        );
+    }
+
+    #[test]
+    fn test_read_bd_addr_display() {
+        let event = CommandCompleteEvent {
+            num_hci_command_packets: 1,
+            command_opcode: HCICommand::ReadBdAddr.opcode(),
+            return_parameters: &[0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66],
+        };
+
+        let CommandReturn::ReadBdAddr(ret) = event.decode_return_parameters() else {
+            panic!("Unexpected return parameters");
+        };
+
+        assert_eq!(ret.status, 0x00);
+        assert_eq!(ret.bd_addr.to_string(), "66:55:44:33:22:11");
+    }
+
+    #[test]
+    fn test_le_read_buffer_size() {
+        assert_eq!(
+            decode(LE_READ_BUFFER_SIZE_COMMAND_COMPLETE),
+            CommandReturn::LEReadBufferSize(LEReadBufferSizeReturn {
+                status: 0x00,
+                le_acl_data_packet_length: 251,
+                total_num_le_acl_data_packets: 15,
+            })
+        );
+    }
+
+    #[test]
+    fn test_le_read_buffer_size_values() {
+        let decode = |return_parameters| {
+            CommandCompleteEvent {
+                num_hci_command_packets: 1,
+                command_opcode: Opcode::from_u16(0x2002),
+                return_parameters,
+            }
+            .decode_return_parameters()
+        };
+
+        // 27 byte packets without data length extension, 8 buffers
+        let CommandReturn::LEReadBufferSize(ret) = decode(&[0x00, 0x1B, 0x00, 0x08]) else {
+            panic!("Unexpected return parameters");
+        };
+        assert_eq!(ret.status(), Ok(ErrorCode::Success));
+        assert_eq!(ret.le_acl_data_packet_length, 27);
+        assert_eq!(ret.total_num_le_acl_data_packets, 8);
+
+        // Buffers shared with BR/EDR
+        let CommandReturn::LEReadBufferSize(ret) = decode(&[0x00, 0x00, 0x00, 0x00]) else {
+            panic!("Unexpected return parameters");
+        };
+        assert_eq!(ret.le_acl_data_packet_length, 0);
+        assert_eq!(ret.total_num_le_acl_data_packets, 0);
+
+        assert_eq!(
+            decode(&[0x00, 0x1B, 0x00]),
+            CommandReturn::Raw(&[0x00, 0x1B, 0x00])
+        );
+    }
+
+    #[test]
+    fn test_le_read_local_supported_features() {
+        let event = CommandCompleteEvent {
+            num_hci_command_packets: 1,
+            command_opcode: Opcode::from_u16(0x2003),
+            return_parameters: &[0x00, 0xFF, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00],
+        };
+
+        let CommandReturn::LEReadLocalSupportedFeatures(ret) = event.decode_return_parameters()
+        else {
+            panic!("Unexpected return parameters");
+        };
+
+        assert_eq!(
+            ret.le_features,
+            [0xFF, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_raw() {
+        // No decoder for HCI_LE_Set_Scan_Parameters
+        assert_eq!(
+            decode(LE_SET_SCAN_PARAMETERS_COMMAND_COMPLETE),
+            CommandReturn::Raw(&[0x00])
+        );
+
+        // Only the status, as some Controllers send when the command failed
+        let event = CommandCompleteEvent {
+            num_hci_command_packets: 1,
+            command_opcode: Opcode::from_u16(0x1009),
+            return_parameters: &[0x0C],
+        };
+        assert_eq!(
+            event.decode_return_parameters(),
+            CommandReturn::Raw(&[0x0C])
+        );
+    }
+}
@@ -0,0 +1,420 @@
+use super::{
+    AddDeviceToResolvingListCommand, EventMask, HCICommand, LEEventMask, PhyMask,
+    ScanEnableCommand, SetAdvertisingSetRandomAddressCommand, SetDefaultPhyCommand,
+    SetScanParametersCommand, address::BdAddr,
+};
+
+// 7.3.1 Set Event Mask command
+pub fn set_event_mask(mask: EventMask) -> HCICommand<'static> {
+    HCICommand::SetEventMask(mask)
+}
+
+// 7.3.2 Reset command
+pub fn reset() -> HCICommand<'static> {
+    HCICommand::Reset
+}
+
+// 7.4.6 Read BD_ADDR command
+pub fn read_bd_addr() -> HCICommand<'static> {
+    HCICommand::ReadBdAddr
+}
+
+// 7.8.1 LE Set Event Mask command
+pub fn le_set_event_mask(mask: LEEventMask) -> HCICommand<'static> {
+    HCICommand::SetLEEventMask(mask)
+}
+
+// 7.8.7 LE Set Advertising Data command
+// Writing the command fails with WriteError::InvalidFormat if data is longer than 31 bytes.
+pub fn le_set_advertising_data(data: &[u8]) -> HCICommand<'_> {
+    HCICommand::SetRawAdvertisingData { data }
+}
+
+// 7.8.9 LE Set Advertising Enable command
+pub fn le_set_advertising_enable(enable: bool) -> HCICommand<'static> {
+    HCICommand::SetAdvertisingEnable {
+        enable: enable as u8,
+    }
+}
+
+// 7.8.10 LE Set Scan Parameters command
+// Writing the command fails with WriteError::InvalidFormat if scan_window is greater than scan_interval.
+pub fn le_set_scan_parameters(
+    scan_type: u8,
+    scan_interval: u16,
+    scan_window: u16,
+    own_address_type: u8,
+    scanning_filter_policy: u8,
+) -> HCICommand<'static> {
+    HCICommand::SetScanParameters(SetScanParametersCommand {
+        scan_type,
+        scan_interval,
+        scan_window,
+        own_address_type,
+        scanning_filter_policy,
+    })
+}
+
+// 7.8.11 LE Set Scan Enable command
+pub fn le_set_scan_enable(enable: bool, filter_duplicates: bool) -> HCICommand<'static> {
+    HCICommand::ScanEnable(ScanEnableCommand {
+        scan_enable: enable as u8,
+        filter_duplicates: filter_duplicates as u8,
+    })
+}
+
+// 7.8.13 LE Create Connection Cancel command
+pub fn le_create_connection_cancel() -> HCICommand<'static> {
+    HCICommand::CreateConnectionCancel
+}
+
+// 7.8.38 LE Add Device To Resolving List command
+pub fn le_add_device_to_resolving_list(
+    peer_identity_address_type: u8,
+    peer_identity_address: BdAddr,
+    peer_irk: [u8; 16],
+    local_irk: [u8; 16],
+) -> HCICommand<'static> {
+    HCICommand::AddDeviceToResolvingList(AddDeviceToResolvingListCommand {
+        peer_identity_address_type,
+        peer_identity_address,
+        peer_irk,
+        local_irk,
+    })
+}
+
+// 7.8.40 LE Clear Resolving List command
+pub fn le_clear_resolving_list() -> HCICommand<'static> {
+    HCICommand::ClearResolvingList
+}
+
+// 7.8.44 LE Set Address Resolution Enable command
+pub fn le_set_address_resolution_enable(enable: bool) -> HCICommand<'static> {
+    HCICommand::SetAddressResolutionEnable {
+        enable: enable as u8,
+    }
+}
+
+// 7.8.48 LE Set Default PHY command
+pub fn le_set_default_phy(all_phys: u8, tx_phys: PhyMask, rx_phys: PhyMask) -> HCICommand<'static> {
+    HCICommand::SetDefaultPhy(SetDefaultPhyCommand {
+        all_phys,
+        tx_phys,
+        rx_phys,
+    })
+}
+
+// 7.8.52 LE Set Advertising Set Random Address command
+pub fn le_set_advertising_set_random_address(
+    advertising_handle: u8,
+    addr: BdAddr,
+) -> HCICommand<'static> {
+    HCICommand::SetAdvertisingSetRandomAddress(SetAdvertisingSetRandomAddressCommand {
+        advertising_handle,
+        random_address: addr,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::writer::WriteError;
+
+    #[test]
+    fn test_reset() {
+        let mut buf = [0; 4];
+
+        let len = reset().write_into(&mut buf).unwrap();
+
+        assert_eq!(&buf[..len], &[0x01, 0x03, 0x0C, 0x00]);
+    }
+
+    #[test]
+    fn test_set_event_mask() {
+        let mask = EventMask::from_bits(
+            EventMask::DISCONNECTION_COMPLETE
+                | EventMask::ENCRYPTION_CHANGE
+                | EventMask::HARDWARE_ERROR
+                | EventMask::LE_META_EVENT,
+        );
+        let mut buf = [0; 12];
+
+        let len = set_event_mask(mask).write_into(&mut buf).unwrap();
+
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, // HCI command packet
+                0x01, 0x0C, // OCF 0x0001, OGF 0x03
+                0x08, // parameter length
+                0x90, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, // event mask, LSB first
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_bd_addr() {
+        let mut buf = [0; 4];
+
+        let len = read_bd_addr().write_into(&mut buf).unwrap();
+
+        assert_eq!(&buf[..len], &[0x01, 0x09, 0x10, 0x00]);
+    }
+
+    #[test]
+    fn test_le_set_event_mask() {
+        let mask = LEEventMask::from_bits(
+            LEEventMask::ADVERTISING_REPORT | LEEventMask::EXTENDED_ADVERTISING_REPORT,
+        );
+        let mut buf = [0; 12];
+
+        let len = le_set_event_mask(mask).write_into(&mut buf).unwrap();
+
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, // HCI command packet
+                0x01, 0x20, // OCF 0x0001, OGF 0x08
+                0x08, // parameter length
+                0x02, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // LE event mask, LSB first
+            ]
+        );
+    }
+
+    #[test]
+    fn test_le_set_advertising_data() {
+        let mut buf = [0; 36];
+        let data = [0x02, 0x01, 0x06, 0x04, 0x09, b'b', b'l', b'e'];
+
+        let len = le_set_advertising_data(&data).write_into(&mut buf).unwrap();
+
+        assert_eq!(len, 36);
+        assert_eq!(&buf[..4], &[0x01, 0x08, 0x20, 0x20]);
+        assert_eq!(buf[4], 8);
+        assert_eq!(&buf[5..13], &data);
+        assert_eq!(&buf[13..], &[0; 23]);
+    }
+
+    #[test]
+    fn test_le_set_advertising_data_too_long() {
+        let mut buf = [0; 64];
+
+        assert!(
+            le_set_advertising_data(&[0xAA; 31])
+                .write_into(&mut buf)
+                .is_ok()
+        );
+        assert!(matches!(
+            le_set_advertising_data(&[0xAA; 32]).write_into(&mut buf),
+            Err(WriteError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn test_le_set_advertising_enable() {
+        let mut buf = [0; 5];
+
+        let len = le_set_advertising_enable(true)
+            .write_into(&mut buf)
+            .unwrap();
+
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, // HCI command packet
+                0x0A, 0x20, // OCF 0x000A, OGF 0x08
+                0x01, // parameter length
+                0x01, // advertising enable
+            ]
+        );
+        assert_eq!(
+            le_set_advertising_enable(false)
+                .write_into(&mut buf)
+                .unwrap(),
+            5
+        );
+        assert_eq!(buf[4], 0x00);
+    }
+
+    #[test]
+    fn test_le_set_scan_parameters() {
+        let mut buf = [0; 11];
+
+        let len = le_set_scan_parameters(0x01, 0x0060, 0x0030, 0x00, 0x00)
+            .write_into(&mut buf)
+            .unwrap();
+
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, // HCI command packet
+                0x0B, 0x20, // OCF 0x000B, OGF 0x08
+                0x07, // parameter length
+                0x01, // active scanning
+                0x60, 0x00, // scan interval 60 ms
+                0x30, 0x00, // scan window 30 ms
+                0x00, // public own address
+                0x00, // accept all advertisements
+            ]
+        );
+    }
+
+    #[test]
+    fn test_le_set_scan_parameters_window_exceeds_interval() {
+        let mut buf = [0; 11];
+
+        assert!(
+            le_set_scan_parameters(0x01, 0x0030, 0x0030, 0x00, 0x00)
+                .write_into(&mut buf)
+                .is_ok()
+        );
+        assert!(matches!(
+            le_set_scan_parameters(0x01, 0x0030, 0x0031, 0x00, 0x00).write_into(&mut buf),
+            Err(WriteError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn test_le_set_scan_enable() {
+        let mut buf = [0; 6];
+
+        let len = le_set_scan_enable(true, false)
+            .write_into(&mut buf)
+            .unwrap();
+
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, // HCI command packet
+                0x0C, 0x20, // OCF 0x000C, OGF 0x08
+                0x02, // parameter length
+                0x01, // scan enable
+                0x00, // filter duplicates
+            ]
+        );
+    }
+
+    #[test]
+    fn test_le_create_connection_cancel() {
+        let mut buf = [0; 8];
+
+        let len = le_create_connection_cancel().write_into(&mut buf).unwrap();
+
+        assert_eq!(&buf[..len], &[0x01, 0x0E, 0x20, 0x00]);
+    }
+
+    #[test]
+    fn test_le_add_device_to_resolving_list() {
+        let addr = BdAddr::from_le_bytes([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        let peer_irk = [
+            0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D,
+            0x1E, 0x1F,
+        ];
+        let local_irk = [
+            0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D,
+            0x2E, 0x2F,
+        ];
+        let mut buf = [0; 64];
+
+        let len = le_add_device_to_resolving_list(0x01, addr, peer_irk, local_irk)
+            .write_into(&mut buf)
+            .unwrap();
+
+        assert_eq!(len, 4 + 39);
+        assert_eq!(&buf[..4], &[0x01, 0x27, 0x20, 39]);
+        assert_eq!(buf[4], 0x01);
+        assert_eq!(&buf[5..11], &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        assert_eq!(&buf[11..27], &peer_irk);
+        assert_eq!(&buf[27..43], &local_irk);
+    }
+
+    #[test]
+    fn test_le_clear_resolving_list() {
+        let mut buf = [0; 8];
+
+        let len = le_clear_resolving_list().write_into(&mut buf).unwrap();
+
+        assert_eq!(&buf[..len], &[0x01, 0x29, 0x20, 0x00]);
+    }
+
+    #[test]
+    fn test_le_set_address_resolution_enable() {
+        let mut buf = [0; 8];
+
+        let len = le_set_address_resolution_enable(true)
+            .write_into(&mut buf)
+            .unwrap();
+
+        assert_eq!(&buf[..len], &[0x01, 0x2D, 0x20, 0x01, 0x01]);
+    }
+
+    #[test]
+    fn test_le_set_default_phy() {
+        let mut buf = [0; 8];
+
+        let len = le_set_default_phy(
+            SetDefaultPhyCommand::NO_RX_PREFERENCE,
+            PhyMask::from_bits(PhyMask::LE_1M | PhyMask::LE_2M),
+            PhyMask::default(),
+        )
+        .write_into(&mut buf)
+        .unwrap();
+
+        assert_eq!(&buf[..len], &[0x01, 0x31, 0x20, 0x03, 0x02, 0x03, 0x00]);
+    }
+
+    #[test]
+    fn test_le_set_default_phy_validation() {
+        let mut buf = [0; 8];
+        let le_2m = PhyMask::from_bits(PhyMask::LE_2M);
+
+        // No TX preference bit, but also no TX PHYs
+        assert!(
+            le_set_default_phy(
+                SetDefaultPhyCommand::NO_RX_PREFERENCE,
+                PhyMask::default(),
+                le_2m
+            )
+            .write_into(&mut buf)
+            .is_err()
+        );
+        // Reserved PHY bit
+        assert!(
+            le_set_default_phy(0x00, PhyMask::from_bits(0b1000), le_2m)
+                .write_into(&mut buf)
+                .is_err()
+        );
+        // Reserved all_phys bit
+        assert!(
+            le_set_default_phy(0b100, le_2m, le_2m)
+                .write_into(&mut buf)
+                .is_err()
+        );
+        assert!(
+            le_set_default_phy(0b11, PhyMask::default(), PhyMask::default())
+                .write_into(&mut buf)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_le_set_advertising_set_random_address() {
+        let addr = BdAddr::from_le_bytes([0x01, 0x02, 0x03, 0x04, 0x05, 0xC6]);
+        let mut buf = [0; 16];
+
+        let len = le_set_advertising_set_random_address(0x02, addr)
+            .write_into(&mut buf)
+            .unwrap();
+
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, // HCI command packet
+                0x35, 0x20, // OCF 0x0035, OGF 0x08
+                0x07, // parameter length
+                0x02, // advertising handle
+                0x01, 0x02, 0x03, 0x04, 0x05, 0xC6, // random address, LSB first
+            ]
+        );
+    }
+}
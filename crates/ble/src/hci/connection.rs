@@ -0,0 +1,167 @@
+use super::{HCICommand, HCIEvent, LEMetaEvent, commands};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InitiatorState {
+    #[default]
+    Idle,
+    Connecting,
+    Cancelling,
+    Connected {
+        connection_handle: u16,
+    },
+}
+
+/// Tracks a single LE Create Connection attempt until the controller reports its outcome.
+///
+/// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.8.13
+/// If the cancel arrives after the connection was already established the controller answers with
+/// Command Disallowed and the attempt completes through the Connection Complete event instead.
+#[derive(Debug, Default)]
+pub struct ConnectionInitiator {
+    state: InitiatorState,
+}
+
+impl ConnectionInitiator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn state(&self) -> InitiatorState {
+        self.state
+    }
+
+    pub fn is_connecting(&self) -> bool {
+        matches!(
+            self.state,
+            InitiatorState::Connecting | InitiatorState::Cancelling
+        )
+    }
+
+    /// Marks that an LE Create Connection command has been sent to the controller.
+    pub fn start(&mut self) {
+        self.state = InitiatorState::Connecting;
+    }
+
+    /// Returns the command to send to abort the attempt, if one is in progress.
+    pub fn cancel(&mut self) -> Option<HCICommand<'static>> {
+        if self.state != InitiatorState::Connecting {
+            return None;
+        }
+
+        self.state = InitiatorState::Cancelling;
+        Some(commands::le_create_connection_cancel())
+    }
+
+    pub fn on_event(&mut self, event: &HCIEvent) {
+        if !self.is_connecting() {
+            return;
+        }
+
+        match event {
+            HCIEvent::LEMetaEvent(LEMetaEvent::ConnectionComplete(event)) => {
+                self.state = match event.status {
+                    0x00 => InitiatorState::Connected {
+                        connection_handle: event.connection_handle,
+                    },
+                    _ => InitiatorState::Idle,
+                };
+            }
+            HCIEvent::CommandComplete(event)
+                if self.state == InitiatorState::Cancelling
+                    && event.command_opcode == HCICommand::CreateConnectionCancel.opcode() =>
+            {
+                if event.return_parameters.first() == Some(&0x00) {
+                    self.state = InitiatorState::Idle;
+                } else {
+                    self.state = InitiatorState::Connecting;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hci::{HCIEventCode, HCIEventPacket};
+
+    const CONNECTION_COMPLETE: [u8; 19] = [
+        0x01, 0x00, 0x40, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x18, 0x00, 0x00,
+        0x00, 0x48, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn test_create_then_complete() {
+        let mut initiator = ConnectionInitiator::new();
+        initiator.start();
+        assert!(initiator.is_connecting());
+
+        let packet = HCIEventPacket::new(
+            HCIEventCode::LEMetaEvent.into(),
+            CONNECTION_COMPLETE.len(),
+            &CONNECTION_COMPLETE,
+        );
+        initiator.on_event(&HCIEvent::from_packet(&packet).unwrap());
+
+        assert_eq!(
+            initiator.state(),
+            InitiatorState::Connected {
+                connection_handle: 0x0040
+            }
+        );
+        assert!(initiator.cancel().is_none());
+    }
+
+    #[test]
+    fn test_create_then_cancel() {
+        let mut initiator = ConnectionInitiator::new();
+        assert!(initiator.cancel().is_none());
+
+        initiator.start();
+        assert!(matches!(
+            initiator.cancel(),
+            Some(HCICommand::CreateConnectionCancel)
+        ));
+        assert_eq!(initiator.state(), InitiatorState::Cancelling);
+
+        let parameters = [0x01, 0x0E, 0x20, 0x00];
+        let packet = HCIEventPacket::new(
+            HCIEventCode::CommandComplete.into(),
+            parameters.len(),
+            &parameters,
+        );
+        initiator.on_event(&HCIEvent::from_packet(&packet).unwrap());
+
+        assert_eq!(initiator.state(), InitiatorState::Idle);
+        assert!(!initiator.is_connecting());
+    }
+
+    #[test]
+    fn test_cancel_too_late() {
+        let mut initiator = ConnectionInitiator::new();
+        initiator.start();
+        initiator.cancel();
+
+        // Command Disallowed, the connection is already being established
+        let parameters = [0x01, 0x0E, 0x20, 0x0C];
+        let packet = HCIEventPacket::new(
+            HCIEventCode::CommandComplete.into(),
+            parameters.len(),
+            &parameters,
+        );
+        initiator.on_event(&HCIEvent::from_packet(&packet).unwrap());
+        assert_eq!(initiator.state(), InitiatorState::Connecting);
+
+        let packet = HCIEventPacket::new(
+            HCIEventCode::LEMetaEvent.into(),
+            CONNECTION_COMPLETE.len(),
+            &CONNECTION_COMPLETE,
+        );
+        initiator.on_event(&HCIEvent::from_packet(&packet).unwrap());
+        assert!(matches!(
+            initiator.state(),
+            InitiatorState::Connected { .. }
+        ));
+    }
+}
@@ -0,0 +1,235 @@
+use core::fmt::Display;
+
+use macros::{IntoU8, TryFromU8};
+
+// Bluetooth Core spec 6.0 | [Vol 1] Part F, Section 1.3 | page 410
+// List of error codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoU8, TryFromU8)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ErrorCode {
+    Success = 0x00,
+    UnknownHCICommand = 0x01,
+    UnknownConnectionIdentifier = 0x02,
+    HardwareFailure = 0x03,
+    PageTimeout = 0x04,
+    AuthenticationFailure = 0x05,
+    PINOrKeyMissing = 0x06,
+    MemoryCapacityExceeded = 0x07,
+    ConnectionTimeout = 0x08,
+    ConnectionLimitExceeded = 0x09,
+    SynchronousConnectionLimitToADeviceExceeded = 0x0A,
+    ConnectionAlreadyExists = 0x0B,
+    CommandDisallowed = 0x0C,
+    ConnectionRejectedDueToLimitedResources = 0x0D,
+    ConnectionRejectedDueToSecurityReasons = 0x0E,
+    ConnectionRejectedDueToUnacceptableBdAddr = 0x0F,
+    ConnectionAcceptTimeoutExceeded = 0x10,
+    UnsupportedFeatureOrParameterValue = 0x11,
+    InvalidHCICommandParameters = 0x12,
+    RemoteUserTerminatedConnection = 0x13,
+    RemoteDeviceTerminatedConnectionDueToLowResources = 0x14,
+    RemoteDeviceTerminatedConnectionDueToPowerOff = 0x15,
+    ConnectionTerminatedByLocalHost = 0x16,
+    RepeatedAttempts = 0x17,
+    PairingNotAllowed = 0x18,
+    UnknownLMPPDU = 0x19,
+    UnsupportedRemoteFeature = 0x1A,
+    SCOOffsetRejected = 0x1B,
+    SCOIntervalRejected = 0x1C,
+    SCOAirModeRejected = 0x1D,
+    InvalidLLParameters = 0x1E,
+    UnspecifiedError = 0x1F,
+    UnsupportedLLParameterValue = 0x20,
+    RoleChangeNotAllowed = 0x21,
+    LLResponseTimeout = 0x22,
+    LLProcedureCollision = 0x23,
+    LMPPDUNotAllowed = 0x24,
+    EncryptionModeNotAcceptable = 0x25,
+    LinkKeyCannotBeChanged = 0x26,
+    RequestedQoSNotSupported = 0x27,
+    InstantPassed = 0x28,
+    PairingWithUnitKeyNotSupported = 0x29,
+    DifferentTransactionCollision = 0x2A,
+    QoSUnacceptableParameter = 0x2C,
+    QoSRejected = 0x2D,
+    ChannelClassificationNotSupported = 0x2E,
+    InsufficientSecurity = 0x2F,
+    ParameterOutOfMandatoryRange = 0x30,
+    RoleSwitchPending = 0x32,
+    ReservedSlotViolation = 0x34,
+    RoleSwitchFailed = 0x35,
+    ExtendedInquiryResponseTooLarge = 0x36,
+    SecureSimplePairingNotSupportedByHost = 0x37,
+    HostBusyPairing = 0x38,
+    ConnectionRejectedDueToNoSuitableChannelFound = 0x39,
+    ControllerBusy = 0x3A,
+    UnacceptableConnectionParameters = 0x3B,
+    AdvertisingTimeout = 0x3C,
+    ConnectionTerminatedDueToMICFailure = 0x3D,
+    ConnectionFailedToBeEstablished = 0x3E,
+    CoarseClockAdjustmentRejected = 0x40,
+    Type0SubmapNotDefined = 0x41,
+    UnknownAdvertisingIdentifier = 0x42,
+    LimitReached = 0x43,
+    OperationCancelledByHost = 0x44,
+    PacketTooLong = 0x45,
+    TooLate = 0x46,
+    TooEarly = 0x47,
+    InsufficientChannels = 0x48,
+}
+
+impl Display for ErrorCode {
+    // The error code names as listed in the spec
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Success => "Success",
+            Self::UnknownHCICommand => "Unknown HCI Command",
+            Self::UnknownConnectionIdentifier => "Unknown Connection Identifier",
+            Self::HardwareFailure => "Hardware Failure",
+            Self::PageTimeout => "Page Timeout",
+            Self::AuthenticationFailure => "Authentication Failure",
+            Self::PINOrKeyMissing => "PIN or Key Missing",
+            Self::MemoryCapacityExceeded => "Memory Capacity Exceeded",
+            Self::ConnectionTimeout => "Connection Timeout",
+            Self::ConnectionLimitExceeded => "Connection Limit Exceeded",
+            Self::SynchronousConnectionLimitToADeviceExceeded => {
+                "Synchronous Connection Limit To A Device Exceeded"
+            }
+            Self::ConnectionAlreadyExists => "Connection Already Exists",
+            Self::CommandDisallowed => "Command Disallowed",
+            Self::ConnectionRejectedDueToLimitedResources => {
+                "Connection Rejected due to Limited Resources"
+            }
+            Self::ConnectionRejectedDueToSecurityReasons => {
+                "Connection Rejected Due To Security Reasons"
+            }
+            Self::ConnectionRejectedDueToUnacceptableBdAddr => {
+                "Connection Rejected due to Unacceptable BD_ADDR"
+            }
+            Self::ConnectionAcceptTimeoutExceeded => "Connection Accept Timeout Exceeded",
+            Self::UnsupportedFeatureOrParameterValue => "Unsupported Feature or Parameter Value",
+            Self::InvalidHCICommandParameters => "Invalid HCI Command Parameters",
+            Self::RemoteUserTerminatedConnection => "Remote User Terminated Connection",
+            Self::RemoteDeviceTerminatedConnectionDueToLowResources => {
+                "Remote Device Terminated Connection due to Low Resources"
+            }
+            Self::RemoteDeviceTerminatedConnectionDueToPowerOff => {
+                "Remote Device Terminated Connection due to Power Off"
+            }
+            Self::ConnectionTerminatedByLocalHost => "Connection Terminated By Local Host",
+            Self::RepeatedAttempts => "Repeated Attempts",
+            Self::PairingNotAllowed => "Pairing Not Allowed",
+            Self::UnknownLMPPDU => "Unknown LMP PDU",
+            Self::UnsupportedRemoteFeature => "Unsupported Remote Feature",
+            Self::SCOOffsetRejected => "SCO Offset Rejected",
+            Self::SCOIntervalRejected => "SCO Interval Rejected",
+            Self::SCOAirModeRejected => "SCO Air Mode Rejected",
+            Self::InvalidLLParameters => "Invalid LMP Parameters / Invalid LL Parameters",
+            Self::UnspecifiedError => "Unspecified Error",
+            Self::UnsupportedLLParameterValue => {
+                "Unsupported LMP Parameter Value / Unsupported LL Parameter Value"
+            }
+            Self::RoleChangeNotAllowed => "Role Change Not Allowed",
+            Self::LLResponseTimeout => "LMP Response Timeout / LL Response Timeout",
+            Self::LLProcedureCollision => {
+                "LMP Error Transaction Collision / LL Procedure Collision"
+            }
+            Self::LMPPDUNotAllowed => "LMP PDU Not Allowed",
+            Self::EncryptionModeNotAcceptable => "Encryption Mode Not Acceptable",
+            Self::LinkKeyCannotBeChanged => "Link Key cannot be Changed",
+            Self::RequestedQoSNotSupported => "Requested QoS Not Supported",
+            Self::InstantPassed => "Instant Passed",
+            Self::PairingWithUnitKeyNotSupported => "Pairing With Unit Key Not Supported",
+            Self::DifferentTransactionCollision => "Different Transaction Collision",
+            Self::QoSUnacceptableParameter => "QoS Unacceptable Parameter",
+            Self::QoSRejected => "QoS Rejected",
+            Self::ChannelClassificationNotSupported => "Channel Classification Not Supported",
+            Self::InsufficientSecurity => "Insufficient Security",
+            Self::ParameterOutOfMandatoryRange => "Parameter Out Of Mandatory Range",
+            Self::RoleSwitchPending => "Role Switch Pending",
+            Self::ReservedSlotViolation => "Reserved Slot Violation",
+            Self::RoleSwitchFailed => "Role Switch Failed",
+            Self::ExtendedInquiryResponseTooLarge => "Extended Inquiry Response Too Large",
+            Self::SecureSimplePairingNotSupportedByHost => {
+                "Secure Simple Pairing Not Supported By Host"
+            }
+            Self::HostBusyPairing => "Host Busy - Pairing",
+            Self::ConnectionRejectedDueToNoSuitableChannelFound => {
+                "Connection Rejected due to No Suitable Channel Found"
+            }
+            Self::ControllerBusy => "Controller Busy",
+            Self::UnacceptableConnectionParameters => "Unacceptable Connection Parameters",
+            Self::AdvertisingTimeout => "Advertising Timeout",
+            Self::ConnectionTerminatedDueToMICFailure => "Connection Terminated due to MIC Failure",
+            Self::ConnectionFailedToBeEstablished => {
+                "Connection Failed to be Established / Synchronization Timeout"
+            }
+            Self::CoarseClockAdjustmentRejected => {
+                "Coarse Clock Adjustment Rejected but Will Try to Adjust Using Clock Dragging"
+            }
+            Self::Type0SubmapNotDefined => "Type0 Submap Not Defined",
+            Self::UnknownAdvertisingIdentifier => "Unknown Advertising Identifier",
+            Self::LimitReached => "Limit Reached",
+            Self::OperationCancelledByHost => "Operation Cancelled by Host",
+            Self::PacketTooLong => "Packet Too Long",
+            Self::TooLate => "Too Late",
+            Self::TooEarly => "Too Early",
+            Self::InsufficientChannels => "Insufficient Channels",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn test_known_codes() {
+        assert_eq!(ErrorCode::try_from(0x00), Ok(ErrorCode::Success));
+        assert_eq!(
+            ErrorCode::try_from(0x02),
+            Ok(ErrorCode::UnknownConnectionIdentifier)
+        );
+        assert_eq!(ErrorCode::try_from(0x08), Ok(ErrorCode::ConnectionTimeout));
+        assert_eq!(
+            ErrorCode::try_from(0x13),
+            Ok(ErrorCode::RemoteUserTerminatedConnection)
+        );
+        assert_eq!(Into::<u8>::into(ErrorCode::InsufficientChannels), 0x48);
+    }
+
+    #[test]
+    fn test_reserved_codes() {
+        assert_eq!(ErrorCode::try_from(0x2B), Err(0x2B));
+        assert_eq!(ErrorCode::try_from(0x49), Err(0x49));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ErrorCode::RemoteUserTerminatedConnection.to_string(),
+            "Remote User Terminated Connection"
+        );
+        assert_eq!(
+            ErrorCode::UnknownConnectionIdentifier.to_string(),
+            "Unknown Connection Identifier"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&ErrorCode::ConnectionTimeout).unwrap();
+
+        assert_eq!(json, r#""ConnectionTimeout""#);
+        assert_eq!(
+            serde_json::from_str::<ErrorCode>(&json).unwrap(),
+            ErrorCode::ConnectionTimeout
+        );
+    }
+}
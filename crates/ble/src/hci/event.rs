@@ -1,25 +1,42 @@
-use core::fmt::Debug;
+use core::{error::Error, fmt::Debug, fmt::Display};
 
-use macros::{FromU8, IntoU8};
-use utils::reader::Reader;
+use macros::{IntoU8, TryFromU8};
+use utils::{
+    reader::Reader,
+    tlv::{LengthField, TlvIterator},
+};
 
 use super::{
-    HCIEventPacket,
-    gap::{AdvertisingData, AdvertisingDataType},
+    HCIEventPacket, Opcode,
+    address::BdAddr,
+    error_code::ErrorCode,
+    gap::{
+        AdvEventProperties, AdvertisingData, AdvertisingDataType, NO_SPECIFIC_CONNECTION_INTERVAL,
+        ServiceUuidIterator, Uuid, UuidSize,
+    },
 };
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7 | page 2240
 // Events
-#[derive(Debug, IntoU8, FromU8)]
+#[derive(Debug, IntoU8, TryFromU8)]
 #[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HCIEventCode {
-    DisconnectionComplete = 0x05, // 7.7.5
-    CommandComplete = 0x0E,       // 7.7.14
-    LEMetaEvent = 0x3E,           // 7.7.65
+    DisconnectionComplete = 0x05,                // 7.7.5
+    EncryptionChangeV1 = 0x08,                   // 7.7.8
+    ReadRemoteVersionInformationComplete = 0x0C, // 7.7.12
+    CommandComplete = 0x0E,                      // 7.7.14
+    CommandStatus = 0x0F,                        // 7.7.15
+    HardwareError = 0x10,                        // 7.7.16
+    NumberOfCompletedPackets = 0x13,             // 7.7.19
+    DataBufferOverflow = 0x1A,                   // 7.7.26
+    LEMetaEvent = 0x3E,                          // 7.7.65
+    AuthenticatedPayloadTimeoutExpired = 0x57,   // 7.7.75
 }
 
-#[derive(Debug, IntoU8, FromU8)]
+#[derive(Debug, IntoU8, TryFromU8)]
 #[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum SubeventCode {
     ConnectionComplete = 0x01,                        // 7.7.65.1
     AdvertisingReport = 0x02,                         // 7.7.65.2
@@ -75,24 +92,34 @@ pub enum SubeventCode {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HCIEvent<'p> {
     DisconnectionComplete(DisconnectionCompleteEvent), // 7.7.5
+    EncryptionChange(EncryptionChangeEvent),           // 7.7.8
+    ReadRemoteVersionInformationComplete(ReadRemoteVersionInformationCompleteEvent), // 7.7.12
     CommandComplete(CommandCompleteEvent<'p>),         // 7.7.14
+    CommandStatus(CommandStatusEvent),                 // 7.7.15
+    HardwareError(HardwareErrorEvent),                 // 7.7.16
+    NumberOfCompletedPackets(NumberOfCompletedPacketsEvent<'p>), // 7.7.19
+    DataBufferOverflow(DataBufferOverflowEvent),       // 7.7.26
     LEMetaEvent(LEMetaEvent<'p>),                      // 7.7.65
+    AuthenticatedPayloadTimeoutExpired(AuthenticatedPayloadTimeoutExpiredEvent), // 7.7.75
+    Unknown { evcode: u8, parameters: &'p [u8] },
 }
 
 #[derive(Debug)]
-pub enum HciParseError<'p> {
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HciParseError {
     InvalidField {
-        field: &'p str,
+        field: &'static str,
         position: usize,
     },
     OutOfBounds {
-        field: &'p str,
+        field: &'static str,
         position: usize,
     },
     InvalidLength {
-        field: &'p str,
+        field: &'static str,
         expected: usize,
         found: usize,
     },
@@ -102,12 +129,52 @@ pub enum HciParseError<'p> {
     },
 }
 
+impl Display for HciParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidField { field, position } => {
+                write!(f, "InvalidField {field} at {position}")
+            }
+            Self::OutOfBounds { field, position } => {
+                write!(f, "OutOfBounds {field} at {position}")
+            }
+            Self::InvalidLength {
+                field,
+                expected,
+                found,
+            } => write!(f, "InvalidLength {field} expected {expected} found {found}"),
+            Self::NotImplemented {
+                evcode,
+                sub_evcode: Some(sub_evcode),
+            } => write!(f, "NotImplemented {evcode:#04x} {sub_evcode:#04x}"),
+            Self::NotImplemented {
+                evcode,
+                sub_evcode: None,
+            } => write!(f, "NotImplemented {evcode:#04x}"),
+        }
+    }
+}
+
+impl Error for HciParseError {}
+
 impl<'p> HCIEvent<'p> {
-    pub fn from_packet(packet: &'p HCIEventPacket) -> Result<HCIEvent<'p>, HciParseError<'p>> {
+    pub fn from_packet(packet: &HCIEventPacket<'p>) -> Result<HCIEvent<'p>, HciParseError> {
+        Self::parse(packet, false)
+    }
+
+    /// Like [`HCIEvent::from_packet`], but events and LE subevents that have no parser yet are
+    /// returned as [`HCIEvent::Unknown`] and [`LEMetaEvent::Unknown`] carrying their raw parameters
+    /// instead of failing the whole packet.
+    pub fn from_packet_lenient(packet: &HCIEventPacket<'p>) -> Result<HCIEvent<'p>, HciParseError> {
+        Self::parse(packet, true)
+    }
+
+    // Only the parameters slice is parsed, packet.len is not trusted to match its length
+    fn parse(packet: &HCIEventPacket<'p>, lenient: bool) -> Result<HCIEvent<'p>, HciParseError> {
         let mut reader = Reader::new(packet.parameters);
 
-        Ok(match packet.evcode.into() {
-            HCIEventCode::DisconnectionComplete => {
+        Ok(match HCIEventCode::try_from(packet.evcode) {
+            Ok(HCIEventCode::DisconnectionComplete) => {
                 HCIEvent::DisconnectionComplete(DisconnectionCompleteEvent {
                     status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
                         field: "status",
@@ -123,32 +190,130 @@ impl<'p> HCIEvent<'p> {
                     })?,
                 })
             }
-            HCIEventCode::CommandComplete => HCIEvent::CommandComplete(CommandCompleteEvent {
+            Ok(HCIEventCode::EncryptionChangeV1) => {
+                HCIEvent::EncryptionChange(EncryptionChangeEvent {
+                    status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                        field: "status",
+                        position: reader.pos,
+                    })?,
+                    connection_handle: reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                        field: "connection_handle",
+                        position: reader.pos,
+                    })?,
+                    encryption_enabled: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                        field: "encryption_enabled",
+                        position: reader.pos,
+                    })?,
+                })
+            }
+            Ok(HCIEventCode::ReadRemoteVersionInformationComplete) => {
+                HCIEvent::ReadRemoteVersionInformationComplete(
+                    ReadRemoteVersionInformationCompleteEvent {
+                        status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                            field: "status",
+                            position: reader.pos,
+                        })?,
+                        connection_handle: reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                            field: "connection_handle",
+                            position: reader.pos,
+                        })?,
+                        version: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                            field: "version",
+                            position: reader.pos,
+                        })?,
+                        company_identifier: reader.read_u16().ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "company_identifier",
+                                position: reader.pos,
+                            },
+                        )?,
+                        subversion: reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                            field: "subversion",
+                            position: reader.pos,
+                        })?,
+                    },
+                )
+            }
+            Ok(HCIEventCode::CommandComplete) => HCIEvent::CommandComplete(CommandCompleteEvent {
                 num_hci_command_packets: reader.read_u8().ok_or(HciParseError::OutOfBounds {
                     field: "num_hci_command_packets",
                     position: reader.pos,
                 })?,
-                command_opcode: reader.read_u16().ok_or(HciParseError::OutOfBounds {
-                    field: "command_opcode",
+                command_opcode: reader.read_u16().map(Opcode::from_u16).ok_or(
+                    HciParseError::OutOfBounds {
+                        field: "command_opcode",
+                        position: reader.pos,
+                    },
+                )?,
+                return_parameters: reader.read_u8_slice(reader.remaining()).ok_or(
+                    HciParseError::OutOfBounds {
+                        field: "return_parameters",
+                        position: reader.pos,
+                    },
+                )?,
+            }),
+            Ok(HCIEventCode::CommandStatus) => HCIEvent::CommandStatus(CommandStatusEvent {
+                status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                    field: "status",
+                    position: reader.pos,
+                })?,
+                num_hci_command_packets: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                    field: "num_hci_command_packets",
                     position: reader.pos,
                 })?,
-                return_parameters: reader.read_u8_slice(packet.len - reader.pos).ok_or(
+                command_opcode: reader.read_u16().map(Opcode::from_u16).ok_or(
                     HciParseError::OutOfBounds {
-                        field: "return_parameters",
+                        field: "command_opcode",
                         position: reader.pos,
                     },
                 )?,
             }),
-            HCIEventCode::LEMetaEvent => HCIEvent::LEMetaEvent(
-                match reader
-                    .read_u8()
-                    .ok_or(HciParseError::OutOfBounds {
-                        field: "sub_event_code",
+            Ok(HCIEventCode::HardwareError) => HCIEvent::HardwareError(HardwareErrorEvent {
+                hardware_code: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                    field: "hardware_code",
+                    position: reader.pos,
+                })?,
+            }),
+            Ok(HCIEventCode::NumberOfCompletedPackets) => {
+                HCIEvent::NumberOfCompletedPackets(NumberOfCompletedPacketsEvent {
+                    num_handles: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                        field: "num_handles",
+                        position: reader.pos,
+                    })?,
+                    reader: reader.sub_reader(reader.remaining()).ok_or(
+                        HciParseError::OutOfBounds {
+                            field: "handles",
+                            position: reader.pos,
+                        },
+                    )?,
+                })
+            }
+            Ok(HCIEventCode::DataBufferOverflow) => {
+                HCIEvent::DataBufferOverflow(DataBufferOverflowEvent {
+                    link_type: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                        field: "link_type",
                         position: reader.pos,
-                    })?
-                    .into()
-                {
-                    SubeventCode::ConnectionComplete => {
+                    })?,
+                })
+            }
+            Ok(HCIEventCode::AuthenticatedPayloadTimeoutExpired) => {
+                HCIEvent::AuthenticatedPayloadTimeoutExpired(
+                    AuthenticatedPayloadTimeoutExpiredEvent {
+                        connection_handle: reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                            field: "connection_handle",
+                            position: reader.pos,
+                        })?,
+                    },
+                )
+            }
+            Ok(HCIEventCode::LEMetaEvent) => {
+                let subevent_code = reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                    field: "sub_event_code",
+                    position: reader.pos,
+                })?;
+
+                HCIEvent::LEMetaEvent(match SubeventCode::try_from(subevent_code) {
+                    Ok(SubeventCode::ConnectionComplete) => {
                         LEMetaEvent::ConnectionComplete(ConnectionCompleteEvent {
                             status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
                                 field: "status",
@@ -170,7 +335,7 @@ impl<'p> HCIEvent<'p> {
                                     position: reader.pos,
                                 },
                             )?,
-                            peer_address: reader.read_u8_slice(6).ok_or(
+                            peer_address: BdAddr::read(&mut reader).ok_or(
                                 HciParseError::OutOfBounds {
                                     field: "peer_address",
                                     position: reader.pos,
@@ -203,23 +368,21 @@ impl<'p> HCIEvent<'p> {
                         })
                     }
 
-                    SubeventCode::AdvertisingReport => {
+                    Ok(SubeventCode::AdvertisingReport) => {
                         LEMetaEvent::AdvertisingReport(AdvertisingReportIterator {
                             num_reports: reader.read_u8().ok_or(HciParseError::OutOfBounds {
                                 field: "num_reports",
                                 position: reader.pos,
                             })?,
-                            reader: Reader::new(
-                                reader.read_u8_slice(packet.len - reader.pos).ok_or(
-                                    HciParseError::OutOfBounds {
-                                        field: "reports",
-                                        position: reader.pos,
-                                    },
-                                )?,
-                            ),
+                            reader: Reader::new(reader.read_u8_slice(reader.remaining()).ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "reports",
+                                    position: reader.pos,
+                                },
+                            )?),
                         })
                     }
-                    SubeventCode::ConnectionUpdateComplete => {
+                    Ok(SubeventCode::ConnectionUpdateComplete) => {
                         LEMetaEvent::ConnectionUpdateComplete(ConnectionUpdateCompleteEvent {
                             status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
                                 field: "status",
@@ -251,217 +414,2713 @@ impl<'p> HCIEvent<'p> {
                             )?,
                         })
                     }
-                    code => {
-                        log::warn!("{:?} is not implemented skipping", code);
-
-                        return Err(HciParseError::NotImplemented {
-                            evcode: HCIEventCode::LEMetaEvent.into(),
-                            sub_evcode: Some(code.into()),
-                        });
+                    Ok(SubeventCode::ExtendedAdvertisingReport) => {
+                        LEMetaEvent::ExtendedAdvertisingReport(ExtendedAdvertisingReportIterator {
+                            num_reports: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "num_reports",
+                                position: reader.pos,
+                            })?,
+                            reader: Reader::new(reader.read_u8_slice(reader.remaining()).ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "reports",
+                                    position: reader.pos,
+                                },
+                            )?),
+                        })
                     }
-                },
-            ),
-        })
-    }
-}
-
-#[derive(Debug)]
-pub struct DisconnectionCompleteEvent {
-    pub status: u8,
-    pub connection_handle: u16,
-    pub reason: u8, // Bluetooth Core Spec 6.0 | [Vol 1] Part F | page 410
-}
-
-#[derive(Debug)]
-pub struct CommandCompleteEvent<'p> {
-    pub num_hci_command_packets: u8,
-    pub command_opcode: u16,
-    pub return_parameters: &'p [u8],
-}
-
-#[derive(Debug)]
-pub enum LEMetaEvent<'p> {
-    ConnectionComplete(ConnectionCompleteEvent<'p>), // 7.7.65.1
-    AdvertisingReport(AdvertisingReportIterator<'p>), // 7.7.65.2
-    ConnectionUpdateComplete(ConnectionUpdateCompleteEvent), // 7.7.65.3
-    ReadAllRemoteFeaturesComplete(&'p [u8]),         // 7.7.65.38
-}
-
-// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.1 | page 2324
-#[derive(Debug)]
-pub struct ConnectionCompleteEvent<'p> {
-    pub status: u8,
-    pub connection_handle: u16,
-    pub role: u8,
-    pub peer_address_type: u8,
-    pub peer_address: &'p [u8],
-    pub connection_interval: u16,
-    pub peripheral_latency: u16,
-    pub supervision_timeout: u16,
-    pub central_clock_accuracy: u8,
-}
-
-// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.2 | page 2327
-#[derive(Debug)]
-pub struct AdvertisingReport<'p> {
-    pub event_type: u8,
-    pub address_type: u8,
-    pub address: &'p [u8],
-    pub data: AdvertisingDataIterator<'p>,
-    pub rssi: i8,
-}
-
-// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.3 | page 2330
-#[derive(Debug)]
-pub struct ConnectionUpdateCompleteEvent {
-    pub status: u8,
-    pub connection_handle: u16,
-    pub connection_interval: u16,
-    pub peripheral_latency: u16,
-    pub supervision_timeout: u16,
-}
-
-#[derive(Debug)]
-pub struct AdvertisingReportIterator<'p> {
-    pub num_reports: u8,
-    pub reader: Reader<'p>,
-}
-
-impl<'p> Iterator for AdvertisingReportIterator<'p> {
-    type Item = AdvertisingReport<'p>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.reader.remaining() == 0 {
-            return None;
-        }
-
-        Some(AdvertisingReport {
-            event_type: self.reader.read_u8()?,
-            address_type: self.reader.read_u8()?,
-            address: self.reader.read_u8_slice(6)?,
-            data: {
-                let len = self.reader.read_u8()? as usize;
-                AdvertisingDataIterator {
-                    reader: Reader::new(self.reader.read_u8_slice(len)?),
-                }
-            },
-            rssi: self.reader.read_u8()? as i8,
-        })
-    }
-}
-
-#[derive(Debug)]
-pub struct AdvertisingDataIterator<'p> {
-    pub reader: Reader<'p>,
-}
-
-impl<'p> Iterator for AdvertisingDataIterator<'p> {
-    type Item = AdvertisingData<'p>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.reader.remaining() == 0 {
-            return None;
-        }
-
-        let len = self.reader.read_u8()? as usize;
-        let ad_type = self.reader.read_u8()?.into();
-        let data = self.reader.read_u8_slice(len - size_of::<u8>())?;
-        let mut reader = Reader::new(data);
-
-        match ad_type {
-            AdvertisingDataType::Flags => Some(AdvertisingData::Flags(reader.read_u8()?)),
-            AdvertisingDataType::IncompleteListOf16BitServiceUUIDs => Some(
-                AdvertisingData::IncompleteListOf16BitServiceUUIDs(reader.read_u16_slice(len)?),
-            ),
-            AdvertisingDataType::CompleteListOf16BitServiceUUIDs => Some(
-                AdvertisingData::CompleteListOf16BitServiceUUIDs(reader.read_u16_slice(len)?),
-            ),
-            AdvertisingDataType::IncompleteListOf32BitServiceUUIDs => Some(
-                AdvertisingData::IncompleteListOf32BitServiceUUIDs(reader.read_u32_slice(len)?),
-            ),
-            AdvertisingDataType::CompleteListOf32BitServiceUUIDs => Some(
-                AdvertisingData::CompleteListOf32BitServiceUUIDs(reader.read_u32_slice(len)?),
-            ),
-            AdvertisingDataType::IncompleteListOf128BitServiceUUIDs => Some(
-                AdvertisingData::IncompleteListOf128BitServiceUUIDs(reader.read_u128_slice(len)?),
-            ),
-            AdvertisingDataType::CompleteListOf128BitServiceUUIDs => Some(
-                AdvertisingData::CompleteListOf128BitServiceUUIDs(reader.read_u128_slice(len)?),
-            ),
-            AdvertisingDataType::ShortenedLocalName => Some(AdvertisingData::ShortenedLocalName(
-                core::str::from_utf8(reader.read_u8_slice(reader.remaining())?).ok()?,
-            )),
-            AdvertisingDataType::CompleteLocalName => Some(AdvertisingData::CompleteLocalName(
-                core::str::from_utf8(reader.read_u8_slice(reader.remaining())?).ok()?,
-            )),
-            AdvertisingDataType::TxPowerLevel => {
-                Some(AdvertisingData::TxPowerLevel(reader.read_u8()? as i8))
-            }
-            AdvertisingDataType::ClassOfDevice => {
-                Some(AdvertisingData::ClassOfDevice(reader.read_u32()?))
-            }
-            AdvertisingDataType::PeripheralConnectionIntervalRange => {
-                Some(AdvertisingData::PeripheralConnectionIntervalRange(
-                    reader.read_u8_slice(reader.remaining())?,
-                ))
-            }
-            AdvertisingDataType::ServiceData => Some(AdvertisingData::ServiceData(
-                reader.read_u8_slice(reader.remaining())?,
-            )),
-            AdvertisingDataType::Appearance => {
-                Some(AdvertisingData::Appearance(reader.read_u16()?))
-            }
-            AdvertisingDataType::LEBluetoothDeviceAddress => {
-                Some(AdvertisingData::LEBluetoothDeviceAddress(
-                    reader.read_u8_slice(reader.remaining())?,
-                ))
-            }
-            AdvertisingDataType::ManufacturerSpecificData => {
-                Some(AdvertisingData::ManufacturerSpecificData(
-                    reader.read_u8_slice(reader.remaining())?,
-                ))
-            }
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_valid_disconnection_complete_event() {
-        let packet = HCIEventPacket {
-            evcode: HCIEventCode::DisconnectionComplete.into(),
-            len: 4,
-            parameters: &[0x00, 0x01, 0x00, 0x00],
-        };
-
-        let event = HCIEvent::from_packet(&packet);
-
-        assert!(event.is_ok());
-
-        log::info!("{:?}", event);
-
-        let event = event.unwrap();
-
-        if let HCIEvent::DisconnectionComplete(event) = event {
-            assert_eq!(event.status, 0x00);
-            assert_eq!(event.connection_handle, 0x0001);
-            assert_eq!(event.reason, 0x13);
-        } else {
-            panic!("Unexpected event type");
-        }
-    }
-
-    #[test]
-    fn test_invalid_disconnection_complete_event() {
-        let packet = HCIEventPacket {
-            evcode: HCIEventCode::DisconnectionComplete.into(),
-            len: 3,                          // Incorrect length
-            parameters: &[0x00, 0x01, 0x00], // Missing Reason field
-        };
-
-        assert!(HCIEvent::from_packet(&packet).is_err());
+                    Ok(SubeventCode::PHYUpdateComplete) => {
+                        LEMetaEvent::PHYUpdateComplete(PHYUpdateCompleteEvent {
+                            status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "status",
+                                position: reader.pos,
+                            })?,
+                            connection_handle: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "connection_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            tx_phy: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "tx_phy",
+                                position: reader.pos,
+                            })?,
+                            rx_phy: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "rx_phy",
+                                position: reader.pos,
+                            })?,
+                        })
+                    }
+                    Ok(SubeventCode::LongTermKeyRequest) => {
+                        LEMetaEvent::LongTermKeyRequest(LongTermKeyRequestEvent {
+                            connection_handle: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "connection_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            random_number: reader.read_u64().ok_or(HciParseError::OutOfBounds {
+                                field: "random_number",
+                                position: reader.pos,
+                            })?,
+                            encrypted_diversifier: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "encrypted_diversifier",
+                                    position: reader.pos,
+                                },
+                            )?,
+                        })
+                    }
+                    Ok(SubeventCode::RemoteConnectionParameterRequest) => {
+                        LEMetaEvent::RemoteConnectionParameterRequest(
+                            RemoteConnectionParameterRequestEvent {
+                                connection_handle: reader.read_u16().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "connection_handle",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                interval_min: reader.read_u16().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "interval_min",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                interval_max: reader.read_u16().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "interval_max",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                max_latency: reader.read_u16().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "max_latency",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                timeout: reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                                    field: "timeout",
+                                    position: reader.pos,
+                                })?,
+                            },
+                        )
+                    }
+                    Ok(
+                        code @ (SubeventCode::EnhancedConnectionCompleteV1
+                        | SubeventCode::EnhancedConnectionCompleteV2),
+                    ) => LEMetaEvent::EnhancedConnectionComplete(EnhancedConnectionCompleteEvent {
+                        status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                            field: "status",
+                            position: reader.pos,
+                        })?,
+                        connection_handle: reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                            field: "connection_handle",
+                            position: reader.pos,
+                        })?,
+                        role: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                            field: "role",
+                            position: reader.pos,
+                        })?,
+                        peer_address_type: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                            field: "peer_address_type",
+                            position: reader.pos,
+                        })?,
+                        peer_address: BdAddr::read(&mut reader).ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "peer_address",
+                                position: reader.pos,
+                            },
+                        )?,
+                        local_resolvable_private_address: BdAddr::read(&mut reader).ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "local_resolvable_private_address",
+                                position: reader.pos,
+                            },
+                        )?,
+                        peer_resolvable_private_address: BdAddr::read(&mut reader).ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "peer_resolvable_private_address",
+                                position: reader.pos,
+                            },
+                        )?,
+                        connection_interval: reader.read_u16().ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "connection_interval",
+                                position: reader.pos,
+                            },
+                        )?,
+                        peripheral_latency: reader.read_u16().ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "peripheral_latency",
+                                position: reader.pos,
+                            },
+                        )?,
+                        supervision_timeout: reader.read_u16().ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "supervision_timeout",
+                                position: reader.pos,
+                            },
+                        )?,
+                        central_clock_accuracy: reader.read_u8().ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "central_clock_accuracy",
+                                position: reader.pos,
+                            },
+                        )?,
+                        // The V2 event appends the advertising and sync handles
+                        advertising_handle: match code {
+                            SubeventCode::EnhancedConnectionCompleteV2 => {
+                                Some(reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                    field: "advertising_handle",
+                                    position: reader.pos,
+                                })?)
+                            }
+                            _ => None,
+                        },
+                        sync_handle: match code {
+                            SubeventCode::EnhancedConnectionCompleteV2 => {
+                                Some(reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                                    field: "sync_handle",
+                                    position: reader.pos,
+                                })?)
+                            }
+                            _ => None,
+                        },
+                    }),
+                    Ok(SubeventCode::AdvertisingSetTerminated) => {
+                        LEMetaEvent::AdvertisingSetTerminated(AdvertisingSetTerminatedEvent {
+                            status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "status",
+                                position: reader.pos,
+                            })?,
+                            advertising_handle: reader.read_u8().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "advertising_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            connection_handle: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "connection_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            num_completed_extended_advertising_events: reader.read_u8().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "num_completed_extended_advertising_events",
+                                    position: reader.pos,
+                                },
+                            )?,
+                        })
+                    }
+                    Ok(SubeventCode::PeriodicAdvertisingSyncEstablished) => {
+                        LEMetaEvent::PeriodicAdvertisingSyncEstablished(
+                            PeriodicAdvertisingSyncEstablishedEvent {
+                                status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                    field: "status",
+                                    position: reader.pos,
+                                })?,
+                                sync_handle: reader.read_u16().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "sync_handle",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                advertising_sid: reader.read_u8().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "advertising_sid",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                advertiser_address_type: reader.read_u8().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "advertiser_address_type",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                advertiser_address: BdAddr::read(&mut reader).ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "advertiser_address",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                advertiser_phy: reader.read_u8().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "advertiser_phy",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                periodic_advertising_interval: reader.read_u16().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "periodic_advertising_interval",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                advertiser_clock_accuracy: reader.read_u8().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "advertiser_clock_accuracy",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                            },
+                        )
+                    }
+                    Ok(SubeventCode::PeriodicAdvertisingReport) => {
+                        LEMetaEvent::PeriodicAdvertisingReport(PeriodicAdvertisingReportEvent {
+                            sync_handle: reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                                field: "sync_handle",
+                                position: reader.pos,
+                            })?,
+                            tx_power: reader.read_i8().ok_or(HciParseError::OutOfBounds {
+                                field: "tx_power",
+                                position: reader.pos,
+                            })?,
+                            rssi: reader.read_i8().ok_or(HciParseError::OutOfBounds {
+                                field: "rssi",
+                                position: reader.pos,
+                            })?,
+                            cte_type: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "cte_type",
+                                position: reader.pos,
+                            })?,
+                            data_status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "data_status",
+                                position: reader.pos,
+                            })?,
+                            data: {
+                                let len = reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                    field: "data_length",
+                                    position: reader.pos,
+                                })? as usize;
+                                AdvertisingDataIterator::new(reader.sub_reader(len).ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "data",
+                                        position: reader.pos,
+                                    },
+                                )?)
+                            },
+                        })
+                    }
+                    Ok(SubeventCode::PeriodicAdvertisingSyncLost) => {
+                        LEMetaEvent::PeriodicAdvertisingSyncLost(PeriodicAdvertisingSyncLostEvent {
+                            sync_handle: reader.read_u16().ok_or(HciParseError::OutOfBounds {
+                                field: "sync_handle",
+                                position: reader.pos,
+                            })?,
+                        })
+                    }
+                    Ok(SubeventCode::ScanTimeout) => LEMetaEvent::ScanTimeout,
+                    Ok(SubeventCode::ReadRemoteFeaturesPage0Complete) => {
+                        LEMetaEvent::ReadRemoteFeaturesComplete(ReadRemoteFeaturesCompleteEvent {
+                            status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "status",
+                                position: reader.pos,
+                            })?,
+                            connection_handle: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "connection_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            features: reader.read_array().ok_or(HciParseError::OutOfBounds {
+                                field: "features",
+                                position: reader.pos,
+                            })?,
+                        })
+                    }
+                    Ok(SubeventCode::ScanRequestReceived) => {
+                        LEMetaEvent::ScanRequestReceived(ScanRequestReceivedEvent {
+                            advertising_handle: reader.read_u8().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "advertising_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            scanner_address_type: reader.read_u8().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "scanner_address_type",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            scanner_address: BdAddr::read(&mut reader).ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "scanner_address",
+                                    position: reader.pos,
+                                },
+                            )?,
+                        })
+                    }
+                    Ok(SubeventCode::ChannelSelectionAlgorithm) => {
+                        LEMetaEvent::ChannelSelectionAlgorithm(ChannelSelectionAlgorithmEvent {
+                            connection_handle: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "connection_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            channel_selection_algorithm: reader.read_u8().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "channel_selection_algorithm",
+                                    position: reader.pos,
+                                },
+                            )?,
+                        })
+                    }
+                    Ok(SubeventCode::ReadAllRemoteFeaturesComplete) => {
+                        LEMetaEvent::ReadAllRemoteFeaturesComplete(
+                            ReadAllRemoteFeaturesCompleteEvent {
+                                status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                    field: "status",
+                                    position: reader.pos,
+                                })?,
+                                connection_handle: reader.read_u16().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "connection_handle",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                max_remote_page: reader.read_u8().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "max_remote_page",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                max_valid_page: reader.read_u8().ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "max_valid_page",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                                features: reader.read_u8_slice(reader.remaining()).ok_or(
+                                    HciParseError::OutOfBounds {
+                                        field: "features",
+                                        position: reader.pos,
+                                    },
+                                )?,
+                            },
+                        )
+                    }
+                    Ok(SubeventCode::PathLossThreshold) => {
+                        LEMetaEvent::PathLossThreshold(PathLossThresholdEvent {
+                            connection_handle: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "connection_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            current_path_loss: reader.read_u8().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "current_path_loss",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            zone_entered: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "zone_entered",
+                                position: reader.pos,
+                            })?,
+                        })
+                    }
+                    Ok(SubeventCode::TransmitPowerReporting) => {
+                        LEMetaEvent::TransmitPowerReporting(TransmitPowerReportingEvent {
+                            status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "status",
+                                position: reader.pos,
+                            })?,
+                            connection_handle: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "connection_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            reason: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "reason",
+                                position: reader.pos,
+                            })?,
+                            phy: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "phy",
+                                position: reader.pos,
+                            })?,
+                            transmit_power_level: reader.read_i8().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "transmit_power_level",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            transmit_power_level_flag: reader.read_u8().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "transmit_power_level_flag",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            delta: reader.read_i8().ok_or(HciParseError::OutOfBounds {
+                                field: "delta",
+                                position: reader.pos,
+                            })?,
+                        })
+                    }
+                    Ok(SubeventCode::SubrateChange) => {
+                        LEMetaEvent::SubrateChange(SubrateChangeEvent {
+                            status: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "status",
+                                position: reader.pos,
+                            })?,
+                            connection_handle: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "connection_handle",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            subrate_factor: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "subrate_factor",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            peripheral_latency: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "peripheral_latency",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            continuation_number: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "continuation_number",
+                                    position: reader.pos,
+                                },
+                            )?,
+                            supervision_timeout: reader.read_u16().ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "supervision_timeout",
+                                    position: reader.pos,
+                                },
+                            )?,
+                        })
+                    }
+                    Ok(SubeventCode::DirectedAdvertisingReport) => {
+                        LEMetaEvent::DirectedAdvertisingReport(DirectedAdvertisingReportIterator {
+                            num_reports: reader.read_u8().ok_or(HciParseError::OutOfBounds {
+                                field: "num_reports",
+                                position: reader.pos,
+                            })?,
+                            reader: Reader::new(reader.read_u8_slice(reader.remaining()).ok_or(
+                                HciParseError::OutOfBounds {
+                                    field: "reports",
+                                    position: reader.pos,
+                                },
+                            )?),
+                        })
+                    }
+                    _ if lenient => LEMetaEvent::Unknown {
+                        subevent_code,
+                        parameters: reader.read_u8_slice(reader.remaining()).ok_or(
+                            HciParseError::OutOfBounds {
+                                field: "parameters",
+                                position: reader.pos,
+                            },
+                        )?,
+                    },
+                    code => {
+                        log::warn!("{:?} is not implemented skipping", code);
+
+                        return Err(HciParseError::NotImplemented {
+                            evcode: HCIEventCode::LEMetaEvent.into(),
+                            sub_evcode: Some(subevent_code),
+                        });
+                    }
+                })
+            }
+            Err(evcode) if lenient => HCIEvent::Unknown {
+                evcode,
+                parameters: reader.read_u8_slice(reader.remaining()).ok_or(
+                    HciParseError::OutOfBounds {
+                        field: "parameters",
+                        position: reader.pos,
+                    },
+                )?,
+            },
+            Err(evcode) => {
+                log::warn!("Event {:#04x} is not implemented skipping", evcode);
+
+                return Err(HciParseError::NotImplemented {
+                    evcode,
+                    sub_evcode: None,
+                });
+            }
+        })
+    }
+}
+
+impl<'p> TryFrom<&HCIEventPacket<'p>> for HCIEvent<'p> {
+    type Error = HciParseError;
+
+    fn try_from(packet: &HCIEventPacket<'p>) -> Result<Self, Self::Error> {
+        Self::from_packet(packet)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisconnectionCompleteEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub reason: u8, // Bluetooth Core Spec 6.0 | [Vol 1] Part F | page 410
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DisconnectReason {
+    LocalHost,
+    RemoteUser,
+    Timeout,
+    Failure(ErrorCode),
+}
+
+impl DisconnectionCompleteEvent {
+    /// Returns the typed status, or the raw value if the code is reserved in the spec.
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+
+    pub fn reason(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.reason)
+    }
+
+    /// Classifies the `reason` of the disconnection.
+    ///
+    /// Reason codes that are reserved in the spec are reported as
+    /// `Failure(ErrorCode::UnspecifiedError)`.
+    pub fn category(&self) -> DisconnectReason {
+        match ErrorCode::try_from(self.reason) {
+            Ok(ErrorCode::ConnectionTerminatedByLocalHost) => DisconnectReason::LocalHost,
+            Ok(
+                ErrorCode::RemoteUserTerminatedConnection
+                | ErrorCode::RemoteDeviceTerminatedConnectionDueToLowResources
+                | ErrorCode::RemoteDeviceTerminatedConnectionDueToPowerOff,
+            ) => DisconnectReason::RemoteUser,
+            Ok(ErrorCode::ConnectionTimeout | ErrorCode::LLResponseTimeout) => {
+                DisconnectReason::Timeout
+            }
+            Ok(code) => DisconnectReason::Failure(code),
+            Err(_) => DisconnectReason::Failure(ErrorCode::UnspecifiedError),
+        }
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.8
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EncryptionChangeEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    // 0x00 encryption off, 0x01 E0 (BR/EDR) or AES-CCM (LE) on, 0x02 AES-CCM on (BR/EDR)
+    pub encryption_enabled: u8,
+}
+
+impl EncryptionChangeEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.12
+// version is the LMP or Link Layer version of the remote device, see the Assigned Numbers document
+// for the version and company identifier values, subversion is manufacturer specific
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadRemoteVersionInformationCompleteEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub version: u8,
+    pub company_identifier: u16,
+    pub subversion: u16,
+}
+
+impl ReadRemoteVersionInformationCompleteEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CommandCompleteEvent<'p> {
+    pub num_hci_command_packets: u8,
+    pub command_opcode: Opcode,
+    pub return_parameters: &'p [u8],
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.15
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommandStatusEvent {
+    pub status: u8,
+    pub num_hci_command_packets: u8,
+    pub command_opcode: Opcode,
+}
+
+impl CommandStatusEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.16
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HardwareErrorEvent {
+    pub hardware_code: u8, // Controller specific
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.19
+// Yields (connection_handle, num_completed_packets) for each handle in the event.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NumberOfCompletedPacketsEvent<'p> {
+    /// Counts down as handles are yielded, bytes past the last handle are never read.
+    pub num_handles: u8,
+    pub reader: Reader<'p>,
+}
+
+impl Iterator for NumberOfCompletedPacketsEvent<'_> {
+    type Item = (u16, u16);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num_handles == 0 {
+            return None;
+        }
+
+        let handle = (self.reader.read_u16()?, self.reader.read_u16()?);
+
+        self.num_handles -= 1;
+
+        Some(handle)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.26
+// The Controller dropped data because its buffers were full, the Host sent more packets than it
+// had credits for
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataBufferOverflowEvent {
+    pub link_type: u8, // 0x00 Synchronous, 0x01 ACL
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LEMetaEvent<'p> {
+    ConnectionComplete(ConnectionCompleteEvent), // 7.7.65.1
+    AdvertisingReport(AdvertisingReportIterator<'p>), // 7.7.65.2
+    ConnectionUpdateComplete(ConnectionUpdateCompleteEvent), // 7.7.65.3
+    ReadRemoteFeaturesComplete(ReadRemoteFeaturesCompleteEvent), // 7.7.65.4
+    LongTermKeyRequest(LongTermKeyRequestEvent), // 7.7.65.5
+    RemoteConnectionParameterRequest(RemoteConnectionParameterRequestEvent), // 7.7.65.6
+    EnhancedConnectionComplete(EnhancedConnectionCompleteEvent), // 7.7.65.10
+    DirectedAdvertisingReport(DirectedAdvertisingReportIterator<'p>), // 7.7.65.11
+    PHYUpdateComplete(PHYUpdateCompleteEvent),   // 7.7.65.12
+    ExtendedAdvertisingReport(ExtendedAdvertisingReportIterator<'p>), // 7.7.65.13
+    PeriodicAdvertisingSyncEstablished(PeriodicAdvertisingSyncEstablishedEvent), // 7.7.65.14
+    PeriodicAdvertisingReport(PeriodicAdvertisingReportEvent<'p>), // 7.7.65.15
+    PeriodicAdvertisingSyncLost(PeriodicAdvertisingSyncLostEvent), // 7.7.65.16
+    ScanTimeout,                                 // 7.7.65.17
+    AdvertisingSetTerminated(AdvertisingSetTerminatedEvent), // 7.7.65.18
+    ScanRequestReceived(ScanRequestReceivedEvent), // 7.7.65.19
+    ChannelSelectionAlgorithm(ChannelSelectionAlgorithmEvent), // 7.7.65.20
+    PathLossThreshold(PathLossThresholdEvent),   // 7.7.65.32
+    TransmitPowerReporting(TransmitPowerReportingEvent), // 7.7.65.33
+    SubrateChange(SubrateChangeEvent),           // 7.7.65.35
+    ReadAllRemoteFeaturesComplete(ReadAllRemoteFeaturesCompleteEvent<'p>), // 7.7.65.38
+    Unknown {
+        subevent_code: u8,
+        parameters: &'p [u8],
+    },
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.1 | page 2324
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConnectionCompleteEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub role: u8,
+    pub peer_address_type: u8,
+    pub peer_address: BdAddr,
+    pub connection_interval: u16,
+    pub peripheral_latency: u16,
+    pub supervision_timeout: u16,
+    pub central_clock_accuracy: u8,
+}
+
+impl ConnectionCompleteEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.2 | page 2327
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvertisingReport<'p> {
+    pub event_type: u8,
+    pub address_type: u8,
+    pub address: BdAddr,
+    pub data: AdvertisingDataIterator<'p>,
+    pub rssi: i8,
+}
+
+impl<'p> AdvertisingReport<'p> {
+    /// Collects the commonly used advertising data elements in a single pass.
+    pub fn summarize(&self) -> AdSummary<'p> {
+        self.data.summarize()
+    }
+
+    /// The complete local name if the advertising data has one, otherwise the shortened name.
+    pub fn local_name(&self) -> Option<&'p str> {
+        self.data.local_name()
+    }
+
+    /// Whether any service UUID list in the advertising data contains `uuid`, comparing 16 and
+    /// 32-bit UUIDs by their 128-bit form. The data iterator itself is left untouched.
+    pub fn advertises_service(&self, uuid: Uuid) -> bool {
+        self.data.advertises_service(uuid)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.3 | page 2330
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConnectionUpdateCompleteEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub connection_interval: u16,
+    pub peripheral_latency: u16,
+    pub supervision_timeout: u16,
+}
+
+impl ConnectionUpdateCompleteEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.4
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadRemoteFeaturesCompleteEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub features: [u8; 8], // [Vol 6] Part B, Section 4.6
+}
+
+impl ReadRemoteFeaturesCompleteEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+
+    /// Returns whether the feature with bit number `bit` of the LE feature set is supported.
+    pub fn supports(&self, bit: u8) -> bool {
+        match self.features.get(bit as usize / 8) {
+            Some(octet) => octet & (1 << (bit % 8)) != 0,
+            None => false,
+        }
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.38
+// features holds the LE feature pages back to back, 8 octets each starting with page 0. Pages past
+// max_valid_page are not meaningful, the Controller may not have read them from the peer.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReadAllRemoteFeaturesCompleteEvent<'p> {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub max_remote_page: u8,
+    pub max_valid_page: u8,
+    pub features: &'p [u8],
+}
+
+impl ReadAllRemoteFeaturesCompleteEvent<'_> {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+
+    /// Returns the feature page `page`, or `None` if it is past `max_valid_page` or missing.
+    pub fn page(&self, page: u8) -> Option<[u8; 8]> {
+        if page > self.max_valid_page {
+            return None;
+        }
+
+        let start = page as usize * 8;
+        self.features.get(start..start + 8)?.try_into().ok()
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.5
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LongTermKeyRequestEvent {
+    pub connection_handle: u16,
+    pub random_number: u64,
+    pub encrypted_diversifier: u16,
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.6
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoteConnectionParameterRequestEvent {
+    pub connection_handle: u16,
+    pub interval_min: u16, // 1.25 ms units
+    pub interval_max: u16, // 1.25 ms units
+    pub max_latency: u16,  // connection events
+    pub timeout: u16,      // 10 ms units
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.10
+// advertising_handle and sync_handle are only present in the V2 event
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnhancedConnectionCompleteEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub role: u8,
+    pub peer_address_type: u8,
+    pub peer_address: BdAddr,
+    pub local_resolvable_private_address: BdAddr,
+    pub peer_resolvable_private_address: BdAddr,
+    pub connection_interval: u16,
+    pub peripheral_latency: u16,
+    pub supervision_timeout: u16,
+    pub central_clock_accuracy: u8,
+    pub advertising_handle: Option<u8>,
+    pub sync_handle: Option<u16>,
+}
+
+impl EnhancedConnectionCompleteEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvertisingReportIterator<'p> {
+    /// Counts down as reports are yielded, a non-zero value once iteration has ended means the
+    /// event was truncated before all of its reports.
+    pub num_reports: u8,
+    pub reader: Reader<'p>,
+}
+
+impl<'p> Iterator for AdvertisingReportIterator<'p> {
+    type Item = AdvertisingReport<'p>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num_reports == 0 {
+            return None;
+        }
+
+        let report = AdvertisingReport {
+            event_type: self.reader.read_u8()?,
+            address_type: self.reader.read_u8()?,
+            address: BdAddr::read(&mut self.reader)?,
+            data: {
+                let len = self.reader.read_u8()? as usize;
+                AdvertisingDataIterator::new(self.reader.sub_reader(len)?)
+            },
+            rssi: self.reader.read_i8()?,
+        };
+
+        self.num_reports -= 1;
+
+        Some(report)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.11
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DirectedAdvertisingReport {
+    pub event_type: u8,
+    pub address_type: u8,
+    pub address: BdAddr,
+    pub direct_address_type: u8,
+    pub direct_address: BdAddr,
+    pub rssi: i8,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DirectedAdvertisingReportIterator<'p> {
+    /// Counts down as reports are yielded, a non-zero value once iteration has ended means the
+    /// event was truncated before all of its reports.
+    pub num_reports: u8,
+    pub reader: Reader<'p>,
+}
+
+impl<'p> Iterator for DirectedAdvertisingReportIterator<'p> {
+    type Item = DirectedAdvertisingReport;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num_reports == 0 {
+            return None;
+        }
+
+        let report = DirectedAdvertisingReport {
+            event_type: self.reader.read_u8()?,
+            address_type: self.reader.read_u8()?,
+            address: BdAddr::read(&mut self.reader)?,
+            direct_address_type: self.reader.read_u8()?,
+            direct_address: BdAddr::read(&mut self.reader)?,
+            rssi: self.reader.read_i8()?,
+        };
+
+        self.num_reports -= 1;
+
+        Some(report)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.12
+// The PHYs are 0x01 for LE 1M, 0x02 for LE 2M and 0x03 for LE Coded
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PHYUpdateCompleteEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub tx_phy: u8,
+    pub rx_phy: u8,
+}
+
+impl PHYUpdateCompleteEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.13
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtendedAdvertisingReport<'p> {
+    pub event_type: AdvEventProperties,
+    pub address_type: u8,
+    pub address: BdAddr,
+    pub primary_phy: u8,
+    pub secondary_phy: u8,
+    pub advertising_sid: u8,
+    pub tx_power: i8,
+    pub rssi: i8,
+    pub periodic_advertising_interval: u16,
+    pub direct_address_type: u8,
+    pub direct_address: BdAddr,
+    pub data: AdvertisingDataIterator<'p>,
+}
+
+impl<'p> ExtendedAdvertisingReport<'p> {
+    /// See [`AdvertisingReport::summarize`].
+    pub fn summarize(&self) -> AdSummary<'p> {
+        self.data.summarize()
+    }
+
+    /// See [`AdvertisingReport::local_name`].
+    pub fn local_name(&self) -> Option<&'p str> {
+        self.data.local_name()
+    }
+
+    /// See [`AdvertisingReport::advertises_service`].
+    pub fn advertises_service(&self, uuid: Uuid) -> bool {
+        self.data.advertises_service(uuid)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtendedAdvertisingReportIterator<'p> {
+    /// Counts down as reports are yielded, a non-zero value once iteration has ended means the
+    /// event was truncated before all of its reports.
+    pub num_reports: u8,
+    pub reader: Reader<'p>,
+}
+
+impl<'p> Iterator for ExtendedAdvertisingReportIterator<'p> {
+    type Item = ExtendedAdvertisingReport<'p>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num_reports == 0 {
+            return None;
+        }
+
+        let report = ExtendedAdvertisingReport {
+            event_type: AdvEventProperties::from_bits(self.reader.read_u16()?),
+            address_type: self.reader.read_u8()?,
+            address: BdAddr::read(&mut self.reader)?,
+            primary_phy: self.reader.read_u8()?,
+            secondary_phy: self.reader.read_u8()?,
+            advertising_sid: self.reader.read_u8()?,
+            tx_power: self.reader.read_i8()?,
+            rssi: self.reader.read_i8()?,
+            periodic_advertising_interval: self.reader.read_u16()?,
+            direct_address_type: self.reader.read_u8()?,
+            direct_address: BdAddr::read(&mut self.reader)?,
+            data: {
+                let len = self.reader.read_u8()? as usize;
+                AdvertisingDataIterator::new(self.reader.sub_reader(len)?)
+            },
+        };
+
+        self.num_reports -= 1;
+
+        Some(report)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.14
+// periodic_advertising_interval is in units of 1.25 ms. advertiser_clock_accuracy ranges from 0x00
+// for 500 ppm to 0x07 for 20 ppm.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeriodicAdvertisingSyncEstablishedEvent {
+    pub status: u8,
+    pub sync_handle: u16,
+    pub advertising_sid: u8,
+    pub advertiser_address_type: u8,
+    pub advertiser_address: BdAddr,
+    pub advertiser_phy: u8,
+    pub periodic_advertising_interval: u16,
+    pub advertiser_clock_accuracy: u8,
+}
+
+impl PeriodicAdvertisingSyncEstablishedEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.15
+// tx_power and rssi are 127 if not available. data_status is 0x00 when the data is complete, 0x01
+// when more reports with the rest of it follow and 0x02 when the Controller gave up receiving it,
+// the AD structures of an incomplete report can be cut off.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicAdvertisingReportEvent<'p> {
+    pub sync_handle: u16,
+    pub tx_power: i8,
+    pub rssi: i8,
+    pub cte_type: u8,
+    pub data_status: u8,
+    pub data: AdvertisingDataIterator<'p>,
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.16
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeriodicAdvertisingSyncLostEvent {
+    pub sync_handle: u16,
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.18
+// connection_handle is only valid if the advertising set terminated because a connection was created
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdvertisingSetTerminatedEvent {
+    pub status: u8,
+    pub advertising_handle: u8,
+    pub connection_handle: u16,
+    pub num_completed_extended_advertising_events: u8,
+}
+
+impl AdvertisingSetTerminatedEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.19
+// Only generated for extended advertising sets with scan request notifications enabled
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScanRequestReceivedEvent {
+    pub advertising_handle: u8,
+    pub scanner_address_type: u8,
+    pub scanner_address: BdAddr,
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.20
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChannelSelectionAlgorithmEvent {
+    pub connection_handle: u16,
+    pub channel_selection_algorithm: u8, // 0x00 Algorithm #1, 0x01 Algorithm #2
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.32
+// current_path_loss is in dB, 0xFF if not available. zone_entered is 0x00 for the low, 0x01 for the
+// middle and 0x02 for the high zone set with HCI_LE_Set_Path_Loss_Reporting_Parameters.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PathLossThresholdEvent {
+    pub connection_handle: u16,
+    pub current_path_loss: u8,
+    pub zone_entered: u8,
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.33
+// reason is 0x00 for a change of the local and 0x01 for a change of the remote transmit power, 0x02
+// for a completed read. transmit_power_level and delta are in dBm, 127 if not available.
+// transmit_power_level_flag has bit 0 set at the minimum and bit 1 set at the maximum level.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransmitPowerReportingEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub reason: u8,
+    pub phy: u8,
+    pub transmit_power_level: i8,
+    pub transmit_power_level_flag: u8,
+    pub delta: i8,
+}
+
+impl TransmitPowerReportingEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.35
+// supervision_timeout is in units of 10 ms
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubrateChangeEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub subrate_factor: u16,
+    pub peripheral_latency: u16,
+    pub continuation_number: u16,
+    pub supervision_timeout: u16,
+}
+
+impl SubrateChangeEvent {
+    pub fn status(&self) -> Result<ErrorCode, u8> {
+        ErrorCode::try_from(self.status)
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.75
+// No packet with a valid MIC was received within the authenticated payload timeout
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthenticatedPayloadTimeoutExpiredEvent {
+    pub connection_handle: u16,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvertisingDataIterator<'p> {
+    tlv: TlvIterator<'p>,
+}
+
+/// The advertising data elements most scanners look at, see [`AdvertisingReport::summarize`].
+///
+/// `local_name` is the complete name if there is one, otherwise the shortened name. The service
+/// UUID lists borrow from the report, a report has at most one list of each size.
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdSummary<'p> {
+    pub flags: Option<u8>,
+    pub local_name: Option<&'p str>,
+    pub tx_power: Option<i8>,
+    pub appearance: Option<u16>,
+    pub service_uuids_16: &'p [u8],
+    pub service_uuids_32: &'p [u8],
+    pub service_uuids_128: &'p [u8],
+}
+
+impl<'p> AdSummary<'p> {
+    pub fn service_uuids(&self) -> impl Iterator<Item = Uuid> + use<'p> {
+        let uuids_16 = ServiceUuidIterator::new(self.service_uuids_16, UuidSize::Uuid16);
+        let uuids_32 = ServiceUuidIterator::new(self.service_uuids_32, UuidSize::Uuid32);
+        let uuids_128 = ServiceUuidIterator::new(self.service_uuids_128, UuidSize::Uuid128);
+
+        uuids_16.chain(uuids_32).chain(uuids_128)
+    }
+}
+
+impl<'p> AdvertisingDataIterator<'p> {
+    // Bluetooth Core spec 6.0 | [Vol 3] Part C, Section 11
+    // Every AD structure starts with a length that includes the AD type, a zero length ends the
+    // significant part of the data and anything after it is padding
+    pub fn new(reader: Reader<'p>) -> Self {
+        Self {
+            tlv: TlvIterator::new(reader, LengthField::U8, true),
+        }
+    }
+
+    /// The reader positioned at the next AD structure.
+    pub fn reader(&self) -> &Reader<'p> {
+        self.tlv.reader()
+    }
+
+    fn summarize(&self) -> AdSummary<'p> {
+        let mut summary = AdSummary::default();
+        let mut shortened_name = None;
+
+        for ad in self.clone() {
+            match ad {
+                AdvertisingData::Flags(flags) => summary.flags = Some(flags),
+                AdvertisingData::CompleteLocalName(name) => summary.local_name = Some(name),
+                AdvertisingData::ShortenedLocalName(name) => shortened_name = Some(name),
+                AdvertisingData::TxPowerLevel(tx_power) => summary.tx_power = Some(tx_power),
+                AdvertisingData::Appearance(appearance) => summary.appearance = Some(appearance),
+                AdvertisingData::IncompleteListOf16BitServiceUUIDs(uuids)
+                | AdvertisingData::CompleteListOf16BitServiceUUIDs(uuids) => {
+                    summary.service_uuids_16 = uuids
+                }
+                AdvertisingData::IncompleteListOf32BitServiceUUIDs(uuids)
+                | AdvertisingData::CompleteListOf32BitServiceUUIDs(uuids) => {
+                    summary.service_uuids_32 = uuids
+                }
+                AdvertisingData::IncompleteListOf128BitServiceUUIDs(uuids)
+                | AdvertisingData::CompleteListOf128BitServiceUUIDs(uuids) => {
+                    summary.service_uuids_128 = uuids
+                }
+                _ => {}
+            }
+        }
+
+        summary.local_name = summary.local_name.or(shortened_name);
+
+        summary
+    }
+
+    fn local_name(&self) -> Option<&'p str> {
+        let mut shortened = None;
+
+        for ad in self.clone() {
+            match ad {
+                AdvertisingData::CompleteLocalName(name) => return Some(name),
+                AdvertisingData::ShortenedLocalName(name) => shortened = Some(name),
+                _ => {}
+            }
+        }
+
+        shortened
+    }
+
+    fn advertises_service(&self, uuid: Uuid) -> bool {
+        let uuid = uuid.to_u128();
+
+        self.clone()
+            .any(|ad| ad.service_uuids().any(|service| service.to_u128() == uuid))
+    }
+}
+
+// A service UUID list has to hold a whole number of UUIDs
+fn service_uuids(data: &[u8], size: UuidSize) -> Option<&[u8]> {
+    data.len().is_multiple_of(size.len()).then_some(data)
+}
+
+impl<'p> Iterator for AdvertisingDataIterator<'p> {
+    type Item = AdvertisingData<'p>;
+
+    // Unknown AD types and known types with a malformed value are both returned as
+    // `AdvertisingData::Unknown`, so a single bad element doesn't end the iteration
+    fn next(&mut self) -> Option<Self::Item> {
+        let (ad_type, data) = self.tlv.next()?;
+
+        let parsed = AdvertisingDataType::try_from(ad_type)
+            .ok()
+            .and_then(|known_type| parse_advertising_data(known_type, data));
+
+        Some(parsed.unwrap_or(AdvertisingData::Unknown { ad_type, data }))
+    }
+}
+
+fn parse_advertising_data(
+    known_type: AdvertisingDataType,
+    data: &[u8],
+) -> Option<AdvertisingData<'_>> {
+    let mut reader = Reader::new(data);
+
+    match known_type {
+        AdvertisingDataType::Flags => Some(AdvertisingData::Flags(reader.read_u8()?)),
+        AdvertisingDataType::IncompleteListOf16BitServiceUUIDs => {
+            Some(AdvertisingData::IncompleteListOf16BitServiceUUIDs(
+                service_uuids(data, UuidSize::Uuid16)?,
+            ))
+        }
+        AdvertisingDataType::CompleteListOf16BitServiceUUIDs => {
+            Some(AdvertisingData::CompleteListOf16BitServiceUUIDs(
+                service_uuids(data, UuidSize::Uuid16)?,
+            ))
+        }
+        AdvertisingDataType::IncompleteListOf32BitServiceUUIDs => {
+            Some(AdvertisingData::IncompleteListOf32BitServiceUUIDs(
+                service_uuids(data, UuidSize::Uuid32)?,
+            ))
+        }
+        AdvertisingDataType::CompleteListOf32BitServiceUUIDs => {
+            Some(AdvertisingData::CompleteListOf32BitServiceUUIDs(
+                service_uuids(data, UuidSize::Uuid32)?,
+            ))
+        }
+        AdvertisingDataType::IncompleteListOf128BitServiceUUIDs => {
+            Some(AdvertisingData::IncompleteListOf128BitServiceUUIDs(
+                service_uuids(data, UuidSize::Uuid128)?,
+            ))
+        }
+        AdvertisingDataType::CompleteListOf128BitServiceUUIDs => {
+            Some(AdvertisingData::CompleteListOf128BitServiceUUIDs(
+                service_uuids(data, UuidSize::Uuid128)?,
+            ))
+        }
+        AdvertisingDataType::ShortenedLocalName => Some(AdvertisingData::ShortenedLocalName(
+            core::str::from_utf8(reader.read_u8_slice(reader.remaining())?).ok()?,
+        )),
+        AdvertisingDataType::CompleteLocalName => Some(AdvertisingData::CompleteLocalName(
+            core::str::from_utf8(reader.read_u8_slice(reader.remaining())?).ok()?,
+        )),
+        AdvertisingDataType::TxPowerLevel => Some(AdvertisingData::TxPowerLevel(reader.read_i8()?)),
+        AdvertisingDataType::ClassOfDevice => {
+            Some(AdvertisingData::ClassOfDevice(reader.read_u32()?))
+        }
+        AdvertisingDataType::PeripheralConnectionIntervalRange => {
+            let interval = |value| match value {
+                NO_SPECIFIC_CONNECTION_INTERVAL => None,
+                value => Some(value),
+            };
+
+            Some(AdvertisingData::PeripheralConnectionIntervalRange {
+                interval_min: interval(reader.read_u16()?),
+                interval_max: interval(reader.read_u16()?),
+            })
+        }
+        AdvertisingDataType::ServiceData => Some(AdvertisingData::ServiceData(
+            reader.read_u8_slice(reader.remaining())?,
+        )),
+        AdvertisingDataType::Appearance => Some(AdvertisingData::Appearance(reader.read_u16()?)),
+        AdvertisingDataType::LEBluetoothDeviceAddress => Some(
+            AdvertisingData::LEBluetoothDeviceAddress(reader.read_u8_slice(reader.remaining())?),
+        ),
+        AdvertisingDataType::ManufacturerSpecificData => {
+            Some(AdvertisingData::ManufacturerSpecificData {
+                company_identifier: reader.read_u16()?,
+                data: reader.read_u8_slice(reader.remaining())?,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hci::{AdvDataStatus, Uuid, fixtures};
+
+    #[test]
+    fn test_valid_disconnection_complete_event() {
+        let packet = fixtures::event_packet(fixtures::DISCONNECTION_COMPLETE_REMOTE_USER);
+
+        let event = HCIEvent::from_packet(&packet);
+
+        assert!(event.is_ok());
+
+        log::info!("{:?}", event);
+
+        let event = event.unwrap();
+
+        if let HCIEvent::DisconnectionComplete(event) = event {
+            assert_eq!(event.status, 0x00);
+            assert_eq!(event.connection_handle, 0x0040);
+            assert_eq!(event.reason, 0x13);
+            assert_eq!(event.category(), DisconnectReason::RemoteUser);
+        } else {
+            panic!("Unexpected event type");
+        }
+    }
+
+    #[test]
+    fn test_invalid_disconnection_complete_event() {
+        let packet = HCIEventPacket {
+            evcode: HCIEventCode::DisconnectionComplete.into(),
+            len: 3,                          // Incorrect length
+            parameters: &[0x00, 0x01, 0x00], // Missing Reason field
+        };
+
+        assert!(HCIEvent::from_packet(&packet).is_err());
+    }
+
+    #[test]
+    fn test_encryption_change_event() {
+        let packet = fixtures::event_packet(fixtures::ENCRYPTION_CHANGE);
+
+        let Ok(HCIEvent::EncryptionChange(event)) = HCIEvent::from_packet(&packet) else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status, 0x00);
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.encryption_enabled, 0x01);
+    }
+
+    #[test]
+    fn test_read_remote_version_information_complete_event() {
+        let packet = fixtures::event_packet(fixtures::READ_REMOTE_VERSION_INFORMATION_COMPLETE);
+
+        let Ok(HCIEvent::ReadRemoteVersionInformationComplete(event)) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status(), Ok(ErrorCode::Success));
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.version, 0x0D);
+        assert_eq!(event.company_identifier, 0x0059);
+        assert_eq!(event.subversion, 0x3110);
+    }
+
+    #[test]
+    fn test_data_buffer_overflow_event() {
+        let packet = fixtures::event_packet(fixtures::DATA_BUFFER_OVERFLOW);
+
+        let Ok(HCIEvent::DataBufferOverflow(event)) = HCIEvent::from_packet(&packet) else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.link_type, 0x01);
+    }
+
+    #[test]
+    fn test_authenticated_payload_timeout_expired_event() {
+        let packet = fixtures::event_packet(fixtures::AUTHENTICATED_PAYLOAD_TIMEOUT_EXPIRED);
+
+        let Ok(HCIEvent::AuthenticatedPayloadTimeoutExpired(event)) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.connection_handle, 0x0040);
+    }
+
+    #[test]
+    fn test_inconsistent_len() {
+        assert!(
+            HCIEventPacket::try_new(HCIEventCode::CommandComplete.into(), 5, &[0; 4]).is_none()
+        );
+
+        // A len shorter than what has already been read must not underflow
+        let packet = HCIEventPacket {
+            evcode: HCIEventCode::CommandComplete.into(),
+            len: 1,
+            parameters: &[0x01, 0x03, 0x0C, 0x00],
+        };
+
+        let Ok(HCIEvent::CommandComplete(event)) = HCIEvent::try_from(&packet) else {
+            panic!("Unexpected event type");
+        };
+        assert_eq!(event.return_parameters, &[0x00]);
+
+        // A len longer than the parameters only sees the parameters
+        let packet = HCIEventPacket {
+            evcode: HCIEventCode::NumberOfCompletedPackets.into(),
+            len: 9,
+            parameters: &[0x01, 0x40, 0x00, 0x02, 0x00],
+        };
+
+        let Ok(HCIEvent::NumberOfCompletedPackets(mut event)) = HCIEvent::try_from(&packet) else {
+            panic!("Unexpected event type");
+        };
+        assert_eq!(event.next(), Some((0x0040, 2)));
+        assert_eq!(event.next(), None);
+    }
+
+    #[test]
+    fn test_command_status_event() {
+        let packet = fixtures::event_packet(fixtures::LE_CREATE_CONNECTION_COMMAND_STATUS);
+
+        let Ok(HCIEvent::CommandStatus(event)) = HCIEvent::from_packet(&packet) else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status, 0x00);
+        assert_eq!(event.num_hci_command_packets, 1);
+        assert_eq!(event.command_opcode.ogf(), 0x08);
+        assert_eq!(event.command_opcode.ocf(), 0x000D);
+    }
+
+    #[test]
+    fn test_hardware_error_event() {
+        let packet = fixtures::event_packet(fixtures::HARDWARE_ERROR);
+
+        let Ok(HCIEvent::HardwareError(event)) = HCIEvent::from_packet(&packet) else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.hardware_code, 0x2A);
+    }
+
+    #[test]
+    fn test_number_of_completed_packets_event() {
+        let packet = fixtures::event_packet(fixtures::NUMBER_OF_COMPLETED_PACKETS);
+
+        let Ok(HCIEvent::NumberOfCompletedPackets(mut event)) = HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.num_handles, 1);
+        assert_eq!(event.next(), Some((0x0040, 2)));
+        assert_eq!(event.next(), None);
+        assert_eq!(event.num_handles, 0);
+    }
+
+    #[test]
+    fn test_number_of_completed_packets_event_trailing_bytes() {
+        let packet = fixtures::event_packet(fixtures::NUMBER_OF_COMPLETED_PACKETS_TRAILING_BYTES);
+
+        let Ok(HCIEvent::NumberOfCompletedPackets(mut event)) = HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.next(), Some((0x0040, 2)));
+        assert_eq!(event.next(), Some((0x0041, 1)));
+        assert_eq!(event.next(), None);
+        assert_eq!(event.reader.remaining(), 4);
+    }
+
+    #[test]
+    fn test_lenient_unknown_subevent() {
+        let packet = HCIEventPacket {
+            evcode: HCIEventCode::LEMetaEvent.into(),
+            len: 11,
+            parameters: &[
+                SubeventCode::DataLengthChange as u8,
+                0x40,
+                0x00,
+                0xFB,
+                0x00,
+                0x48,
+                0x08,
+                0xFB,
+                0x00,
+                0x48,
+                0x08,
+            ],
+        };
+
+        assert!(HCIEvent::from_packet(&packet).is_err());
+
+        let event = HCIEvent::from_packet_lenient(&packet);
+
+        assert!(event.is_ok());
+
+        if let HCIEvent::LEMetaEvent(LEMetaEvent::Unknown {
+            subevent_code,
+            parameters,
+        }) = event.unwrap()
+        {
+            assert_eq!(subevent_code, 0x07);
+            assert_eq!(
+                parameters,
+                &[0x40, 0x00, 0xFB, 0x00, 0x48, 0x08, 0xFB, 0x00, 0x48, 0x08]
+            );
+        } else {
+            panic!("Unexpected event type");
+        }
+    }
+
+    #[test]
+    fn test_lenient_undefined_subevent() {
+        let packet = HCIEventPacket {
+            evcode: HCIEventCode::LEMetaEvent.into(),
+            len: 3,
+            parameters: &[0xF0, 0xAA, 0xBB],
+        };
+
+        assert!(HCIEvent::from_packet(&packet).is_err());
+
+        if let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::Unknown {
+            subevent_code,
+            parameters,
+        })) = HCIEvent::from_packet_lenient(&packet)
+        {
+            assert_eq!(subevent_code, 0xF0);
+            assert_eq!(parameters, &[0xAA, 0xBB]);
+        } else {
+            panic!("Unexpected event type");
+        }
+    }
+
+    #[test]
+    fn test_unknown_event_code() {
+        let packet = fixtures::event_packet(fixtures::VENDOR_SPECIFIC_EVENT);
+
+        assert!(matches!(
+            HCIEvent::from_packet(&packet),
+            Err(HciParseError::NotImplemented {
+                evcode: 0xFF,
+                sub_evcode: None,
+            })
+        ));
+
+        let Ok(HCIEvent::Unknown { evcode, parameters }) = HCIEvent::from_packet_lenient(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+        assert_eq!(evcode, 0xFF);
+        assert_eq!(parameters, &[0x01, 0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn test_advertising_report_multiple_reports() {
+        // Each report is laid out sequentially, the fields of report i+1 follow the RSSI of report i:
+        //
+        // | Subevent_Code | Num_Reports | Event_Type[0] | Address_Type[0] | Address[0] (6) | Data_Length[0] | Data[0] (Data_Length[0]) | RSSI[0] | Event_Type[1] | ... | RSSI[Num_Reports - 1] |
+        let packet = fixtures::event_packet(fixtures::LE_ADVERTISING_REPORT_MULTIPLE);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::AdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(reports.num_reports, 3);
+
+        let mut report = reports.next().unwrap();
+        assert_eq!(report.event_type, 0x00);
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+        );
+        assert!(report.data.next().is_none());
+        assert_eq!(report.rssi, -40);
+
+        let mut report = reports.next().unwrap();
+        assert_eq!(report.event_type, 0x02);
+        assert_eq!(report.address_type, 0x01);
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x11, 0x12, 0x13, 0x14, 0x15, 0x16]
+        );
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::Flags(0x06))
+        ));
+        assert!(report.data.next().is_none());
+        assert_eq!(report.rssi, -50);
+
+        let mut report = reports.next().unwrap();
+        assert_eq!(report.event_type, 0x04);
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x21, 0x22, 0x23, 0x24, 0x25, 0x26]
+        );
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::Flags(0x06))
+        ));
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::CompleteLocalName("ble!"))
+        ));
+        assert!(report.data.next().is_none());
+        assert_eq!(report.rssi, -60);
+
+        assert!(reports.next().is_none());
+    }
+
+    #[test]
+    fn test_advertising_report_truncated() {
+        let mut reports = AdvertisingReportIterator {
+            num_reports: 2,
+            reader: Reader::new(&[
+                // report 0: ADV_IND, no AD
+                0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0xD8,
+                // report 1: cut off inside the address
+                0x00, 0x00, 0x11, 0x12,
+            ]),
+        };
+
+        assert_eq!(reports.next().unwrap().rssi, -40);
+        assert!(reports.next().is_none());
+        assert_eq!(reports.num_reports, 1);
+    }
+
+    #[test]
+    fn test_advertising_report_trailing_bytes() {
+        let mut reports = AdvertisingReportIterator {
+            num_reports: 1,
+            reader: Reader::new(&[
+                // report 0: ADV_IND, no AD
+                0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0xD8,
+                // not covered by num_reports
+                0x00, 0x00, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x00, 0xCE,
+            ]),
+        };
+
+        assert_eq!(reports.next().unwrap().rssi, -40);
+        assert!(reports.next().is_none());
+        assert_eq!(reports.num_reports, 0);
+        assert_eq!(reports.reader.remaining(), 10);
+    }
+
+    #[test]
+    fn test_phy_update_complete_event() {
+        let packet = fixtures::event_packet(fixtures::LE_PHY_UPDATE_COMPLETE);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::PHYUpdateComplete(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status, 0x00);
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.tx_phy, 0x02);
+        assert_eq!(event.rx_phy, 0x02);
+    }
+
+    #[test]
+    fn test_extended_advertising_report() {
+        let packet = fixtures::event_packet(fixtures::LE_EXTENDED_ADVERTISING_REPORT);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::ExtendedAdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(reports.num_reports, 2);
+
+        let mut report = reports.next().unwrap();
+        assert_eq!(
+            report.event_type,
+            AdvEventProperties::legacy_connectable_scannable()
+        );
+        assert!(!report.event_type.is_scan_response());
+        assert_eq!(report.event_type.data_status(), Ok(AdvDataStatus::Complete));
+        assert_eq!(report.address_type, 0x01);
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0xC1, 0xC2, 0xC3, 0xC4, 0xC5, 0xC6]
+        );
+        assert_eq!(report.primary_phy, 0x01);
+        assert_eq!(report.secondary_phy, 0x00);
+        assert_eq!(report.advertising_sid, 0xFF);
+        assert_eq!(report.tx_power, 127);
+        assert_eq!(report.rssi, -60);
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::Flags(0x06))
+        ));
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::CompleteLocalName("ble!"))
+        ));
+        assert!(report.data.next().is_none());
+
+        let mut report = reports.next().unwrap();
+        assert_eq!(
+            report.event_type,
+            AdvEventProperties::extended_connectable()
+        );
+        assert!(!report.event_type.is_legacy());
+        assert!(!report.event_type.is_scannable());
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x11, 0x12, 0x13, 0x14, 0x15, 0x16]
+        );
+        assert_eq!(report.primary_phy, 0x03);
+        assert_eq!(report.secondary_phy, 0x02);
+        assert_eq!(report.advertising_sid, 0x05);
+        assert_eq!(report.tx_power, -10);
+        assert_eq!(report.rssi, -80);
+        assert!(report.data.next().is_none());
+
+        assert!(reports.next().is_none());
+    }
+
+    #[test]
+    fn test_long_term_key_request_event() {
+        let packet = fixtures::event_packet(fixtures::LE_LONG_TERM_KEY_REQUEST);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::LongTermKeyRequest(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.random_number, 0x0807060504030201);
+        assert_eq!(event.encrypted_diversifier, 0x1234);
+    }
+
+    #[test]
+    fn test_remote_connection_parameter_request_event() {
+        let packet = fixtures::event_packet(fixtures::LE_REMOTE_CONNECTION_PARAMETER_REQUEST);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::RemoteConnectionParameterRequest(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.interval_min, 6);
+        assert_eq!(event.interval_max, 12);
+        assert_eq!(event.max_latency, 0);
+        assert_eq!(event.timeout, 200);
+    }
+
+    fn assert_enhanced_connection_complete(event: &EnhancedConnectionCompleteEvent) {
+        assert_eq!(event.status, 0x00);
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.role, 0x01);
+        assert_eq!(event.peer_address_type, 0x02);
+        assert_eq!(
+            event.peer_address.to_le_bytes(),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+        );
+        assert_eq!(
+            event.local_resolvable_private_address.to_le_bytes(),
+            [0x11, 0x12, 0x13, 0x14, 0x15, 0x56]
+        );
+        assert_eq!(
+            event.peer_resolvable_private_address.to_le_bytes(),
+            [0x21, 0x22, 0x23, 0x24, 0x25, 0x66]
+        );
+        assert_eq!(event.connection_interval, 0x0018);
+        assert_eq!(event.peripheral_latency, 0x0000);
+        assert_eq!(event.supervision_timeout, 0x0048);
+        assert_eq!(event.central_clock_accuracy, 0x00);
+    }
+
+    #[test]
+    fn test_enhanced_connection_complete_v1_event() {
+        let packet = fixtures::event_packet(fixtures::LE_ENHANCED_CONNECTION_COMPLETE_V1);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::EnhancedConnectionComplete(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_enhanced_connection_complete(&event);
+        assert_eq!(event.advertising_handle, None);
+        assert_eq!(event.sync_handle, None);
+    }
+
+    #[test]
+    fn test_enhanced_connection_complete_v2_event() {
+        let packet = fixtures::event_packet(fixtures::LE_ENHANCED_CONNECTION_COMPLETE_V2);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::EnhancedConnectionComplete(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_enhanced_connection_complete(&event);
+        assert_eq!(event.advertising_handle, Some(0x01));
+        assert_eq!(event.sync_handle, Some(0x0FFF));
+    }
+
+    #[test]
+    fn test_advertising_set_terminated_event() {
+        let packet = fixtures::event_packet(fixtures::LE_ADVERTISING_SET_TERMINATED);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::AdvertisingSetTerminated(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status, 0x00);
+        assert_eq!(event.advertising_handle, 0x01);
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.num_completed_extended_advertising_events, 5);
+    }
+
+    #[test]
+    fn test_scan_timeout_event() {
+        let packet = fixtures::event_packet(fixtures::LE_SCAN_TIMEOUT);
+
+        assert!(matches!(
+            HCIEvent::from_packet(&packet),
+            Ok(HCIEvent::LEMetaEvent(LEMetaEvent::ScanTimeout))
+        ));
+    }
+
+    #[test]
+    fn test_read_remote_features_complete_event() {
+        let packet = fixtures::event_packet(fixtures::LE_READ_REMOTE_FEATURES_COMPLETE);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::ReadRemoteFeaturesComplete(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status, 0x00);
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(
+            event.features,
+            [0x20, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_read_remote_features_supports() {
+        let event = ReadRemoteFeaturesCompleteEvent {
+            status: 0x00,
+            connection_handle: 0x0040,
+            features: [0x20, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80],
+        };
+
+        assert!(event.supports(5)); // LE Data Packet Length Extension
+        assert!(event.supports(8)); // LE 2M PHY
+        assert!(event.supports(63));
+        assert!(!event.supports(0));
+        assert!(!event.supports(9));
+        assert!(!event.supports(64));
+    }
+
+    #[test]
+    fn test_path_loss_threshold_event() {
+        let packet = fixtures::event_packet(fixtures::LE_PATH_LOSS_THRESHOLD);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::PathLossThreshold(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.current_path_loss, 70);
+        assert_eq!(event.zone_entered, 0x02);
+    }
+
+    #[test]
+    fn test_transmit_power_reporting_event() {
+        let packet = fixtures::event_packet(fixtures::LE_TRANSMIT_POWER_REPORTING);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::TransmitPowerReporting(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status(), Ok(ErrorCode::Success));
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.reason, 0x01);
+        assert_eq!(event.phy, 0x02);
+        assert_eq!(event.transmit_power_level, -12);
+        assert_eq!(event.transmit_power_level_flag, 0x01);
+        assert_eq!(event.delta, -4);
+    }
+
+    #[test]
+    fn test_subrate_change_event() {
+        let packet = fixtures::event_packet(fixtures::LE_SUBRATE_CHANGE);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::SubrateChange(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status(), Ok(ErrorCode::Success));
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.subrate_factor, 4);
+        assert_eq!(event.peripheral_latency, 1);
+        assert_eq!(event.continuation_number, 2);
+        assert_eq!(event.supervision_timeout, 500);
+    }
+
+    #[test]
+    fn test_read_all_remote_features_complete_event() {
+        let packet = fixtures::event_packet(fixtures::LE_READ_ALL_REMOTE_FEATURES_COMPLETE);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::ReadAllRemoteFeaturesComplete(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status(), Ok(ErrorCode::Success));
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.max_remote_page, 0x02);
+        assert_eq!(event.max_valid_page, 0x01);
+        assert_eq!(event.features.len(), 16);
+        assert_eq!(
+            event.page(0),
+            Some([0x20, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00])
+        );
+        assert_eq!(
+            event.page(1),
+            Some([0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00])
+        );
+        assert_eq!(event.page(2), None);
+    }
+
+    #[test]
+    fn test_read_all_remote_features_complete_event_truncated() {
+        let packet =
+            fixtures::event_packet(fixtures::LE_READ_ALL_REMOTE_FEATURES_COMPLETE_TRUNCATED);
+
+        assert!(matches!(
+            HCIEvent::from_packet(&packet),
+            Err(HciParseError::OutOfBounds {
+                field: "max_remote_page",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_periodic_advertising_sync_established_event() {
+        let packet = fixtures::event_packet(fixtures::LE_PERIODIC_ADVERTISING_SYNC_ESTABLISHED);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::PeriodicAdvertisingSyncEstablished(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status(), Ok(ErrorCode::Success));
+        assert_eq!(event.sync_handle, 0x0001);
+        assert_eq!(event.advertising_sid, 0x03);
+        assert!(BdAddr::is_public(event.advertiser_address_type));
+        assert_eq!(
+            event.advertiser_address,
+            BdAddr::from_le_bytes([0x66, 0x55, 0x44, 0x33, 0x22, 0x11])
+        );
+        assert_eq!(event.advertiser_phy, 0x02);
+        assert_eq!(event.periodic_advertising_interval, 80);
+        assert_eq!(event.advertiser_clock_accuracy, 0x05);
+    }
+
+    #[test]
+    fn test_periodic_advertising_report_event() {
+        let packet = fixtures::event_packet(fixtures::LE_PERIODIC_ADVERTISING_REPORT);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::PeriodicAdvertisingReport(mut event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.sync_handle, 0x0001);
+        assert_eq!(event.tx_power, 127);
+        assert_eq!(event.rssi, -60);
+        assert_eq!(event.cte_type, 0xFF);
+        assert_eq!(event.data_status, 0x00);
+        assert!(matches!(
+            event.data.next(),
+            Some(AdvertisingData::Flags(0x06))
+        ));
+        assert!(matches!(
+            event.data.next(),
+            Some(AdvertisingData::CompleteLocalName("ble!"))
+        ));
+        assert!(event.data.next().is_none());
+    }
+
+    #[test]
+    fn test_periodic_advertising_report_event_truncated_data() {
+        let packet =
+            fixtures::event_packet(fixtures::LE_PERIODIC_ADVERTISING_REPORT_TRUNCATED_DATA);
+
+        assert!(matches!(
+            HCIEvent::from_packet(&packet),
+            Err(HciParseError::OutOfBounds { field: "data", .. })
+        ));
+    }
+
+    #[test]
+    fn test_periodic_advertising_sync_lost_event() {
+        let packet = fixtures::event_packet(fixtures::LE_PERIODIC_ADVERTISING_SYNC_LOST);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::PeriodicAdvertisingSyncLost(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.sync_handle, 0x0001);
+    }
+
+    #[test]
+    fn test_scan_request_received_event() {
+        let packet = fixtures::event_packet(fixtures::LE_SCAN_REQUEST_RECEIVED);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::ScanRequestReceived(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.advertising_handle, 0x01);
+        assert!(BdAddr::is_random(event.scanner_address_type));
+        assert_eq!(
+            event.scanner_address,
+            BdAddr::from_le_bytes([0x66, 0x55, 0x44, 0x33, 0x22, 0xC1])
+        );
+    }
+
+    #[test]
+    fn test_channel_selection_algorithm_event() {
+        let packet = fixtures::event_packet(fixtures::LE_CHANNEL_SELECTION_ALGORITHM);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::ChannelSelectionAlgorithm(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.channel_selection_algorithm, 0x01);
+    }
+
+    #[test]
+    fn test_directed_advertising_report() {
+        let packet = fixtures::event_packet(fixtures::LE_DIRECTED_ADVERTISING_REPORT);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::DirectedAdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(reports.num_reports, 1);
+
+        let report = reports.next().unwrap();
+        assert_eq!(report.event_type, 0x01);
+        assert_eq!(report.address_type, 0x00);
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+        );
+        assert_eq!(report.direct_address_type, 0x02);
+        assert_eq!(
+            report.direct_address.to_le_bytes(),
+            [0x11, 0x12, 0x13, 0x14, 0x15, 0x56]
+        );
+        assert_eq!(report.rssi, -40);
+
+        assert!(reports.next().is_none());
+    }
+
+    #[test]
+    fn test_directed_advertising_report_multiple_reports() {
+        let packet = fixtures::event_packet(fixtures::LE_DIRECTED_ADVERTISING_REPORT_MULTIPLE);
+
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::DirectedAdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(reports.num_reports, 2);
+
+        let report = reports.next().unwrap();
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+        );
+        assert_eq!(report.rssi, -40);
+
+        let report = reports.next().unwrap();
+        assert_eq!(report.address_type, 0x01);
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x21, 0x22, 0x23, 0x24, 0x25, 0xC6]
+        );
+        assert_eq!(
+            report.direct_address.to_le_bytes(),
+            [0x11, 0x12, 0x13, 0x14, 0x15, 0x56]
+        );
+        assert_eq!(report.rssi, -50);
+
+        assert!(reports.next().is_none());
+    }
+
+    #[test]
+    fn test_disconnection_category() {
+        let event = |reason| DisconnectionCompleteEvent {
+            status: 0x00,
+            connection_handle: 0x0001,
+            reason,
+        };
+
+        assert_eq!(event(0x13).category(), DisconnectReason::RemoteUser);
+        assert_eq!(event(0x16).category(), DisconnectReason::LocalHost);
+        assert_eq!(event(0x08).category(), DisconnectReason::Timeout);
+        assert_eq!(
+            event(0x3D).category(),
+            DisconnectReason::Failure(ErrorCode::ConnectionTerminatedDueToMICFailure)
+        );
+        assert_eq!(
+            event(0x2B).category(),
+            DisconnectReason::Failure(ErrorCode::UnspecifiedError)
+        );
+    }
+
+    #[test]
+    fn test_disconnection_status_and_reason() {
+        let event = DisconnectionCompleteEvent {
+            status: 0x00,
+            connection_handle: 0x0001,
+            reason: 0x13,
+        };
+
+        assert_eq!(event.status(), Ok(ErrorCode::Success));
+        assert_eq!(
+            event.reason(),
+            Ok(ErrorCode::RemoteUserTerminatedConnection)
+        );
+        assert_eq!(
+            DisconnectionCompleteEvent {
+                reason: 0x2B,
+                ..event
+            }
+            .reason(),
+            Err(0x2B)
+        );
+    }
+
+    #[test]
+    fn test_advertises_service() {
+        let buf = [
+            0x03, 0x03, 0x0D, 0x18, // Complete List of 16-bit Service UUIDs, Heart Rate
+            0x02, 0x01, 0x06, // Flags
+            0x05, 0x09, b'b', b'l', b'e', b'!', // Complete Local Name
+        ];
+        let report = AdvertisingReport {
+            event_type: 0x00,
+            address_type: 0x00,
+            address: BdAddr::default(),
+            data: AdvertisingDataIterator::new(Reader::new(&buf)),
+            rssi: -40,
+        };
+
+        assert!(report.advertises_service(Uuid::Uuid16(0x180D)));
+        assert!(report.advertises_service(Uuid::Uuid32(0x0000180D)));
+        assert!(report.advertises_service(Uuid::Uuid128(0x0000180D_0000_1000_8000_00805F9B34FB)));
+        assert!(!report.advertises_service(Uuid::Uuid16(0x180F)));
+        assert!(!report.advertises_service(Uuid::Uuid128(0x0000180D_0000_1000_8000_00805F9B34FC)));
+
+        // The report data is not consumed
+        assert_eq!(report.data.count(), 3);
+    }
+
+    #[test]
+    fn test_advertises_service_unaligned_report() {
+        let packet = fixtures::event_packet(fixtures::LE_ADVERTISING_REPORT_HEART_RATE);
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::AdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+        let report = reports.next().unwrap();
+
+        assert!(report.advertises_service(Uuid::Uuid16(0x180D)));
+        assert!(report.advertises_service(Uuid::Uuid128(0x0000180D_0000_1000_8000_00805F9B34FB)));
+        assert!(!report.advertises_service(Uuid::Uuid16(0x180F)));
+    }
+
+    #[test]
+    fn test_local_name() {
+        let report = |data| AdvertisingReport {
+            event_type: 0x00,
+            address_type: 0x00,
+            address: BdAddr::default(),
+            data: AdvertisingDataIterator::new(Reader::new(data)),
+            rssi: -40,
+        };
+
+        let complete = [0x02, 0x01, 0x06, 0x05, 0x09, b'b', b'l', b'e', b'!'];
+        assert_eq!(report(&complete).local_name(), Some("ble!"));
+
+        let shortened = [0x03, 0x08, b'b', b'l', 0x02, 0x0A, 0xF8];
+        assert_eq!(report(&shortened).local_name(), Some("bl"));
+
+        // The complete name wins regardless of the order
+        let both = [0x03, 0x08, b'b', b'l', 0x04, 0x09, b'b', b'l', b'e'];
+        assert_eq!(report(&both).local_name(), Some("ble"));
+
+        let neither = [0x02, 0x01, 0x06, 0x02, 0x0A, 0xF8];
+        assert_eq!(report(&neither).local_name(), None);
+    }
+
+    #[test]
+    fn test_summarize() {
+        let buf = [
+            0x05, 0x03, 0x0D, 0x18, 0x0F, 0x18, // Complete List of 16-bit Service UUIDs
+            0x02, 0x01, 0x06, // Flags
+            0x03, 0x19, 0x41, 0x03, // Appearance, running walking sensor
+            0x03, 0x08, b'b', b'l', // Shortened Local Name
+            0x02, 0x0A, 0xF8, // Tx Power Level
+            0x05, 0x09, b'b', b'l', b'e', b'!', // Complete Local Name
+            0x03, 0xFF, 0x59, 0x00, // Manufacturer Specific Data
+        ];
+        let report = AdvertisingReport {
+            event_type: 0x00,
+            address_type: 0x00,
+            address: BdAddr::default(),
+            data: AdvertisingDataIterator::new(Reader::new(&buf)),
+            rssi: -40,
+        };
+
+        let summary = report.summarize();
+
+        assert_eq!(summary.flags, Some(0x06));
+        assert_eq!(summary.local_name, Some("ble!"));
+        assert_eq!(summary.tx_power, Some(-8));
+        assert_eq!(summary.appearance, Some(0x0341));
+        assert_eq!(summary.service_uuids_16, &[0x0D, 0x18, 0x0F, 0x18]);
+        assert!(summary.service_uuids_32.is_empty());
+        assert!(summary.service_uuids_128.is_empty());
+        assert!(
+            summary
+                .service_uuids()
+                .eq([Uuid::Uuid16(0x180D), Uuid::Uuid16(0x180F)])
+        );
+    }
+
+    #[test]
+    fn test_summarize_unaligned_report() {
+        let packet = fixtures::event_packet(fixtures::LE_ADVERTISING_REPORT_HEART_RATE);
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::AdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        let summary = reports.next().unwrap().summarize();
+
+        assert_eq!(summary.flags, Some(0x06));
+        assert!(summary.service_uuids().eq([Uuid::Uuid16(0x180D)]));
+        assert_eq!(summary.tx_power, Some(-8));
+        assert_eq!(summary.local_name, Some("HRM"));
+        assert_eq!(summary.appearance, None);
+    }
+
+    #[test]
+    fn test_summarize_empty() {
+        let report = AdvertisingReport {
+            event_type: 0x00,
+            address_type: 0x00,
+            address: BdAddr::default(),
+            data: AdvertisingDataIterator::new(Reader::new(&[0x03, 0x08, b'b', b'l'])),
+            rssi: -40,
+        };
+
+        assert_eq!(
+            report.summarize(),
+            AdSummary {
+                local_name: Some("bl"),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_advertises_service_without_uuids() {
+        let report = AdvertisingReport {
+            event_type: 0x00,
+            address_type: 0x00,
+            address: BdAddr::default(),
+            data: AdvertisingDataIterator::new(Reader::new(&[0x02, 0x01, 0x06])),
+            rssi: -40,
+        };
+
+        assert!(!report.advertises_service(Uuid::Uuid16(0x180D)));
+    }
+
+    #[test]
+    fn test_advertising_data_16_bit_uuid_lists() {
+        let one = [0x03, 0x03, 0x0D, 0x18];
+        let two = [0x05, 0x02, 0x0D, 0x18, 0x0F, 0x18];
+        let three = [0x07, 0x03, 0x0D, 0x18, 0x0F, 0x18, 0x0A, 0x18];
+
+        let mut data = AdvertisingDataIterator::new(Reader::new(&one));
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::CompleteListOf16BitServiceUUIDs(&[
+                0x0D, 0x18
+            ]))
+        );
+        assert_eq!(data.next(), None);
+
+        let mut data = AdvertisingDataIterator::new(Reader::new(&two));
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::IncompleteListOf16BitServiceUUIDs(&[
+                0x0D, 0x18, 0x0F, 0x18
+            ]))
+        );
+        assert_eq!(data.next(), None);
+
+        let mut data = AdvertisingDataIterator::new(Reader::new(&three));
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::CompleteListOf16BitServiceUUIDs(&[
+                0x0D, 0x18, 0x0F, 0x18, 0x0A, 0x18
+            ]))
+        );
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_advertising_data_unaligned_uuid_list() {
+        // The list starts at an odd offset, as it usually does behind a Flags element
+        let buf = [0x02, 0x01, 0x06, 0x03, 0x03, 0x0D, 0x18, 0x02, 0x0A, 0xF8];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert_eq!(data.next(), Some(AdvertisingData::Flags(0x06)));
+        assert!(
+            data.next()
+                .unwrap()
+                .service_uuids()
+                .eq([Uuid::Uuid16(0x180D)])
+        );
+        assert_eq!(data.next(), Some(AdvertisingData::TxPowerLevel(-8)));
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_advertising_data_32_and_128_bit_uuid_lists() {
+        let buf = [
+            0x05, 0x05, 0x0D, 0x18, 0x00, 0x00, // Complete List of 32-bit Service UUIDs
+            0x11, 0x06, 0xFB, 0x34, 0x9B, 0x5F, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00,
+            0x0F, 0x18, 0x00, 0x00, // Incomplete List of 128-bit Service UUIDs
+        ];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert!(
+            data.next()
+                .unwrap()
+                .service_uuids()
+                .eq([Uuid::Uuid32(0x0000180D)])
+        );
+        assert!(
+            data.next()
+                .unwrap()
+                .service_uuids()
+                .eq([Uuid::Uuid128(0x0000180F_0000_1000_8000_00805F9B34FB)])
+        );
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_advertising_data_uuid_list_followed_by_element() {
+        let buf = [0x05, 0x03, 0x0D, 0x18, 0x0F, 0x18, 0x02, 0x01, 0x06];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert!(
+            data.next()
+                .unwrap()
+                .service_uuids()
+                .eq([Uuid::Uuid16(0x180D), Uuid::Uuid16(0x180F)])
+        );
+        assert_eq!(data.next(), Some(AdvertisingData::Flags(0x06)));
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_advertising_data_uuid_list_truncated() {
+        // Declares two 16-bit UUIDs but only carries three bytes of them
+        let buf = [0x04, 0x03, 0x0D, 0x18, 0x0F];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::Unknown {
+                ad_type: 0x03,
+                data: &[0x0D, 0x18, 0x0F],
+            })
+        );
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_advertising_data_malformed_element_followed_by_element() {
+        let buf = [
+            0x01, 0x01, // Flags without a value
+            0x03, 0x09, 0xC3, 0x28, // Complete Local Name, invalid UTF-8
+            0x04, 0x03, 0x0D, 0x18, 0x0F, // Complete List of 16-bit Service UUIDs, odd length
+            0x02, 0x0A, 0xF8, // Tx Power Level
+        ];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::Unknown {
+                ad_type: 0x01,
+                data: &[],
+            })
+        );
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::Unknown {
+                ad_type: 0x09,
+                data: &[0xC3, 0x28],
+            })
+        );
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::Unknown {
+                ad_type: 0x03,
+                data: &[0x0D, 0x18, 0x0F],
+            })
+        );
+        assert_eq!(data.next(), Some(AdvertisingData::TxPowerLevel(-8)));
+        assert_eq!(data.next(), None);
+
+        let report = AdvertisingReport {
+            event_type: 0x00,
+            address_type: 0x00,
+            address: BdAddr::default(),
+            data: AdvertisingDataIterator::new(Reader::new(&buf)),
+            rssi: -40,
+        };
+        assert_eq!(report.summarize().tx_power, Some(-8));
+    }
+
+    #[test]
+    fn test_advertising_data_zero_length_element() {
+        let buf = [0x02, 0x01, 0x06, 0x00, 0x03, 0x09, b'b', b'l'];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert_eq!(data.next(), Some(AdvertisingData::Flags(0x06)));
+        assert_eq!(data.next(), None);
+
+        let mut data = AdvertisingDataIterator::new(Reader::new(&[0x00; 31]));
+
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_advertising_data_unknown_type() {
+        let buf = [0x02, 0x01, 0x06, 0x03, 0x3D, 0xAA, 0xBB, 0x02, 0x0A, 0xF8];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert_eq!(data.next(), Some(AdvertisingData::Flags(0x06)));
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::Unknown {
+                ad_type: 0x3D,
+                data: &[0xAA, 0xBB]
+            })
+        );
+        assert_eq!(data.next(), Some(AdvertisingData::TxPowerLevel(-8)));
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_advertising_data_peripheral_connection_interval_range() {
+        let buf = [
+            0x05, 0x12, 0x06, 0x00, 0x80, 0x0C, 0x05, 0x12, 0xFF, 0xFF, 0x20, 0x00,
+        ];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::PeripheralConnectionIntervalRange {
+                interval_min: Some(0x0006),
+                interval_max: Some(0x0C80),
+            })
+        );
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::PeripheralConnectionIntervalRange {
+                interval_min: None,
+                interval_max: Some(0x0020),
+            })
+        );
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_advertising_data_manufacturer_specific_data() {
+        let buf = [0x05, 0xFF, 0x4C, 0x00, 0x02, 0x15, 0x02, 0xFF, 0x4C];
+        let mut data = AdvertisingDataIterator::new(Reader::new(&buf));
+
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::ManufacturerSpecificData {
+                company_identifier: 0x004C,
+                data: &[0x02, 0x15],
+            })
+        );
+        // Too short to carry a company identifier
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::Unknown {
+                ad_type: 0xFF,
+                data: &[0x4C],
+            })
+        );
+        assert_eq!(data.next(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let event = DisconnectionCompleteEvent {
+            status: 0x00,
+            connection_handle: 0x0040,
+            reason: 0x13,
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(json, r#"{"status":0,"connection_handle":64,"reason":19}"#);
+
+        let event: DisconnectionCompleteEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(
+            event.reason(),
+            Ok(ErrorCode::RemoteUserTerminatedConnection)
+        );
+
+        let event = ConnectionCompleteEvent {
+            status: 0x00,
+            connection_handle: 0x0040,
+            role: 0x00,
+            peer_address_type: 0x00,
+            peer_address: BdAddr::from_le_bytes([0x22, 0x11, 0x00, 0xEE, 0xFF, 0xC0]),
+            connection_interval: 0x0018,
+            peripheral_latency: 0x0000,
+            supervision_timeout: 0x0048,
+            central_clock_accuracy: 0x00,
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        let parsed: ConnectionCompleteEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.peer_address, event.peer_address);
+        assert_eq!(parsed.supervision_timeout, event.supervision_timeout);
     }
 }
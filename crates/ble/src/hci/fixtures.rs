@@ -0,0 +1,960 @@
+//! Annotated H4 packets (packet type byte included) shared by the parser tests.
+//!
+//! The fixtures are synthetic, they are built by hand from the packet layouts in the Bluetooth Core
+//! spec rather than captured from a controller. The bytes follow the layout controllers put on the
+//! wire for each packet, with the decoded values noted next to every field. Fixtures for events that have no parser yet are kept here as well so
+//! that new parsers can be tested against them.
+
+use super::{HCIEventPacket, HCIPacket};
+
+/// Command Complete for HCI_Reset
+///
+/// num_hci_command_packets = 1, command_opcode = 0x0C03, status = Success
+pub const RESET_COMMAND_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x0E, // Command Complete
+    0x04, // parameter length
+    0x01, // num_hci_command_packets
+    0x03, 0x0C, // command_opcode
+    0x00, // status
+];
+
+/// Command Complete for HCI_LE_Set_Scan_Parameters
+///
+/// num_hci_command_packets = 1, command_opcode = 0x200B, status = Success
+pub const LE_SET_SCAN_PARAMETERS_COMMAND_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x0E, // Command Complete
+    0x04, // parameter length
+    0x01, // num_hci_command_packets
+    0x0B, 0x20, // command_opcode
+    0x00, // status
+];
+
+/// Command Complete for HCI_LE_Set_Advertising_Enable while already advertising
+///
+/// num_hci_command_packets = 1, command_opcode = 0x200A, status = Command Disallowed
+pub const LE_SET_ADVERTISING_ENABLE_COMMAND_DISALLOWED: &[u8] = &[
+    0x04, // event packet
+    0x0E, // Command Complete
+    0x04, // parameter length
+    0x01, // num_hci_command_packets
+    0x0A, 0x20, // command_opcode
+    0x0C, // status
+];
+
+/// Command Complete for HCI_Read_BD_ADDR
+///
+/// num_hci_command_packets = 1, command_opcode = 0x1009, status = Success,
+/// bd_addr = 11:22:33:44:55:66
+pub const READ_BD_ADDR_COMMAND_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x0E, // Command Complete
+    0x0A, // parameter length
+    0x01, // num_hci_command_packets
+    0x09, 0x10, // command_opcode
+    0x00, // status
+    0x66, 0x55, 0x44, 0x33, 0x22, 0x11, // bd_addr, LSB first
+];
+
+/// Command Complete for HCI_LE_Read_Buffer_Size [v1]
+///
+/// num_hci_command_packets = 1, command_opcode = 0x2002, status = Success,
+/// le_acl_data_packet_length = 251, total_num_le_acl_data_packets = 15
+pub const LE_READ_BUFFER_SIZE_COMMAND_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x0E, // Command Complete
+    0x07, // parameter length
+    0x01, // num_hci_command_packets
+    0x02, 0x20, // command_opcode
+    0x00, // status
+    0xFB, 0x00, // le_acl_data_packet_length
+    0x0F, // total_num_le_acl_data_packets
+];
+
+/// Command Status for HCI_LE_Create_Connection
+///
+/// status = Success, num_hci_command_packets = 1, command_opcode = 0x200D
+pub const LE_CREATE_CONNECTION_COMMAND_STATUS: &[u8] = &[
+    0x04, // event packet
+    0x0F, // Command Status
+    0x04, // parameter length
+    0x00, // status
+    0x01, // num_hci_command_packets
+    0x0D, 0x20, // command_opcode
+];
+
+/// Disconnection Complete after the peer terminated the link
+///
+/// status = Success, connection_handle = 0x0040, reason = Remote User Terminated Connection
+pub const DISCONNECTION_COMPLETE_REMOTE_USER: &[u8] = &[
+    0x04, // event packet
+    0x05, // Disconnection Complete
+    0x04, // parameter length
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x13, // reason
+];
+
+/// Disconnection Complete after the supervision timeout expired
+///
+/// status = Success, connection_handle = 0x0041, reason = Connection Timeout
+pub const DISCONNECTION_COMPLETE_TIMEOUT: &[u8] = &[
+    0x04, // event packet
+    0x05, // Disconnection Complete
+    0x04, // parameter length
+    0x00, // status
+    0x41, 0x00, // connection_handle
+    0x08, // reason
+];
+
+/// Number Of Completed Packets for a single connection
+///
+/// num_handles = 1, connection_handle = 0x0040, num_completed_packets = 2
+pub const NUMBER_OF_COMPLETED_PACKETS: &[u8] = &[
+    0x04, // event packet
+    0x13, // Number Of Completed Packets
+    0x05, // parameter length
+    0x01, // num_handles
+    0x40, 0x00, // connection_handle[0]
+    0x02, 0x00, // num_completed_packets[0]
+];
+
+/// Number Of Completed Packets for two connections followed by a pair not covered by num_handles
+///
+/// num_handles = 2, handles = [(0x0040, 2), (0x0041, 1)]
+pub const NUMBER_OF_COMPLETED_PACKETS_TRAILING_BYTES: &[u8] = &[
+    0x04, // event packet
+    0x13, // Number Of Completed Packets
+    0x0D, // parameter length
+    0x02, // num_handles
+    0x40, 0x00, // connection_handle[0]
+    0x02, 0x00, // num_completed_packets[0]
+    0x41, 0x00, // connection_handle[1]
+    0x01, 0x00, // num_completed_packets[1]
+    0x42, 0x00, 0x05, 0x00, // past num_handles, must not be read as credits
+];
+
+/// Encryption Change [v1] after encryption was turned on for a connection
+///
+/// status = Success, connection_handle = 0x0040, encryption_enabled = On (AES-CCM)
+pub const ENCRYPTION_CHANGE: &[u8] = &[
+    0x04, // event packet
+    0x08, // Encryption Change [v1]
+    0x04, // parameter length
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x01, // encryption_enabled
+];
+
+/// Hardware Error reported by the controller
+///
+/// hardware_code = 0x2A, controller specific
+pub const HARDWARE_ERROR: &[u8] = &[
+    0x04, // event packet
+    0x10, // Hardware Error
+    0x01, // parameter length
+    0x2A, // hardware_code
+];
+
+/// Read Remote Version Information Complete for a Nordic peer
+///
+/// status = Success, connection_handle = 0x0040, version = Bluetooth Core 5.4,
+/// company_identifier = 0x0059 (Nordic Semiconductor ASA), subversion = 0x3110
+pub const READ_REMOTE_VERSION_INFORMATION_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x0C, // Read Remote Version Information Complete
+    0x08, // parameter length
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x0D, // version
+    0x59, 0x00, // company_identifier
+    0x10, 0x31, // subversion
+];
+
+/// Data Buffer Overflow after the host sent more ACL packets than it had credits for
+///
+/// link_type = ACL
+pub const DATA_BUFFER_OVERFLOW: &[u8] = &[
+    0x04, // event packet
+    0x1A, // Data Buffer Overflow
+    0x01, // parameter length
+    0x01, // link_type
+];
+
+/// Authenticated Payload Timeout Expired for an encrypted connection
+///
+/// connection_handle = 0x0040
+pub const AUTHENTICATED_PAYLOAD_TIMEOUT_EXPIRED: &[u8] = &[
+    0x04, // event packet
+    0x57, // Authenticated Payload Timeout Expired
+    0x02, // parameter length
+    0x40, 0x00, // connection_handle
+];
+
+/// LE Connection Complete as central
+///
+/// status = Success, connection_handle = 0x0040, role = Central, peer_address_type = Public,
+/// peer_address = C0:FF:EE:00:11:22, connection_interval = 0x0018 (30 ms),
+/// peripheral_latency = 0, supervision_timeout = 0x0048 (720 ms), central_clock_accuracy = 0x00
+pub const LE_CONNECTION_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x13, // parameter length
+    0x01, // LE Connection Complete
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x00, // role
+    0x00, // peer_address_type
+    0x22, 0x11, 0x00, 0xEE, 0xFF, 0xC0, // peer_address, LSB first
+    0x18, 0x00, // connection_interval
+    0x00, 0x00, // peripheral_latency
+    0x48, 0x00, // supervision_timeout
+    0x00, // central_clock_accuracy
+];
+
+/// LE Connection Update Complete
+///
+/// status = Success, connection_handle = 0x0040, connection_interval = 0x0006 (7.5 ms),
+/// peripheral_latency = 0, supervision_timeout = 0x01F4 (5 s)
+pub const LE_CONNECTION_UPDATE_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x0A, // parameter length
+    0x03, // LE Connection Update Complete
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x06, 0x00, // connection_interval
+    0x00, 0x00, // peripheral_latency
+    0xF4, 0x01, // supervision_timeout
+];
+
+/// LE Advertising Report with a single ADV_IND
+///
+/// event_type = ADV_IND, address_type = Random, address = D6:5A:12:34:56:78,
+/// data = [Flags(0x06), CompleteLocalName("ble!")], rssi = -67
+pub const LE_ADVERTISING_REPORT_ADV_IND: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x15, // parameter length
+    0x02, // LE Advertising Report
+    0x01, // num_reports
+    0x00, // event_type
+    0x01, // address_type
+    0x78, 0x56, 0x34, 0x12, 0x5A, 0xD6, // address, LSB first
+    0x09, // data length
+    0x02, 0x01, 0x06, // Flags
+    0x05, 0x09, b'b', b'l', b'e', b'!', // Complete Local Name
+    0xBD, // rssi
+];
+
+/// LE Advertising Report with a single SCAN_RSP
+///
+/// event_type = SCAN_RSP, address_type = Public, address = 00:1A:7D:DA:71:13,
+/// data = [ShortenedLocalName("Mi"), TxPowerLevel(-8)], rssi = -80
+pub const LE_ADVERTISING_REPORT_SCAN_RSP: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x13, // parameter length
+    0x02, // LE Advertising Report
+    0x01, // num_reports
+    0x04, // event_type
+    0x00, // address_type
+    0x13, 0x71, 0xDA, 0x7D, 0x1A, 0x00, // address, LSB first
+    0x07, // data length
+    0x03, 0x08, b'M', b'i', // Shortened Local Name
+    0x02, 0x0A, 0xF8, // Tx Power Level
+    0xB0, // rssi
+];
+
+/// LE Advertising Report of a heart rate sensor, its service UUID list starts at an odd offset
+///
+/// event_type = ADV_IND, address_type = Public, address = 00:1A:7D:DA:71:13,
+/// data = [Flags(0x06), CompleteListOf16BitServiceUUIDs([0x180D]), TxPowerLevel(-8),
+/// CompleteLocalName("HRM")], rssi = -58
+pub const LE_ADVERTISING_REPORT_HEART_RATE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x1B, // parameter length
+    0x02, // LE Advertising Report
+    0x01, // num_reports
+    0x00, // event_type
+    0x00, // address_type
+    0x13, 0x71, 0xDA, 0x7D, 0x1A, 0x00, // address, LSB first
+    0x0F, // data length
+    0x02, 0x01, 0x06, // Flags
+    0x03, 0x03, 0x0D, 0x18, // Complete List of 16-bit Service UUIDs
+    0x02, 0x0A, 0xF8, // Tx Power Level
+    0x04, 0x09, b'H', b'R', b'M', // Complete Local Name
+    0xC6, // rssi
+];
+
+/// LE PHY Update Complete after switching to LE 2M
+///
+/// status = Success, connection_handle = 0x0040, tx_phy = LE 2M, rx_phy = LE 2M
+pub const LE_PHY_UPDATE_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x06, // parameter length
+    0x0C, // LE PHY Update Complete
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x02, // tx_phy
+    0x02, // rx_phy
+];
+
+/// LE Advertising Report carrying three reports
+///
+/// reports = [
+///     ADV_IND from 06:05:04:03:02:01 (Public), data = [], rssi = -40,
+///     ADV_SCAN_IND from 16:15:14:13:12:11 (Random), data = [Flags(0x06)], rssi = -50,
+///     SCAN_RSP from 26:25:24:23:22:21 (Public), data = [Flags(0x06), CompleteLocalName("ble!")],
+///     rssi = -60,
+/// ]
+pub const LE_ADVERTISING_REPORT_MULTIPLE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x2C, // parameter length
+    0x02, // LE Advertising Report
+    0x03, // num_reports
+    // report 0
+    0x00, // event_type
+    0x00, // address_type
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // address, LSB first
+    0x00, // data length
+    0xD8, // rssi
+    // report 1
+    0x02, // event_type
+    0x01, // address_type
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x16, // address, LSB first
+    0x03, // data length
+    0x02, 0x01, 0x06, // Flags
+    0xCE, // rssi
+    // report 2
+    0x04, // event_type
+    0x00, // address_type
+    0x21, 0x22, 0x23, 0x24, 0x25, 0x26, // address, LSB first
+    0x09, // data length
+    0x02, 0x01, 0x06, // Flags
+    0x05, 0x09, b'b', b'l', b'e', b'!', // Complete Local Name
+    0xC4, // rssi
+];
+
+/// LE Directed Advertising Report with a single ADV_DIRECT_IND
+///
+/// event_type = ADV_DIRECT_IND, address_type = Public, address = 06:05:04:03:02:01,
+/// direct_address_type = Random (resolvable), direct_address = 56:15:14:13:12:11, rssi = -40
+pub const LE_DIRECTED_ADVERTISING_REPORT: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x12, // parameter length
+    0x0B, // LE Directed Advertising Report
+    0x01, // num_reports
+    0x01, // event_type
+    0x00, // address_type
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // address, LSB first
+    0x02, // direct_address_type
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x56, // direct_address, LSB first
+    0xD8, // rssi
+];
+
+/// LE Directed Advertising Report carrying two reports to the same resolvable address
+///
+/// reports = [
+///     ADV_DIRECT_IND from 06:05:04:03:02:01 (Public), rssi = -40,
+///     ADV_DIRECT_IND from C6:25:24:23:22:21 (Random), rssi = -50,
+/// ], direct_address = 56:15:14:13:12:11
+pub const LE_DIRECTED_ADVERTISING_REPORT_MULTIPLE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x22, // parameter length
+    0x0B, // LE Directed Advertising Report
+    0x02, // num_reports
+    // report 0
+    0x01, // event_type
+    0x00, // address_type
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // address, LSB first
+    0x02, // direct_address_type
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x56, // direct_address, LSB first
+    0xD8, // rssi
+    // report 1
+    0x01, // event_type
+    0x01, // address_type
+    0x21, 0x22, 0x23, 0x24, 0x25, 0xC6, // address, LSB first
+    0x02, // direct_address_type
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x56, // direct_address, LSB first
+    0xCE, // rssi
+];
+
+/// LE Extended Advertising Report carrying a legacy and an extended report
+///
+/// reports = [
+///     ADV_IND from C6:C5:C4:C3:C2:C1 (Random) on LE 1M, tx_power unavailable, rssi = -60,
+///     data = [Flags(0x06), CompleteLocalName("ble!")],
+///     extended connectable from 16:15:14:13:12:11 (Public) on LE Coded with an LE 2M secondary,
+///     advertising_sid = 5, tx_power = -10, rssi = -80, data = [],
+/// ]
+pub const LE_EXTENDED_ADVERTISING_REPORT: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x3B, // parameter length
+    0x0D, // LE Extended Advertising Report
+    0x02, // num_reports
+    // report 0
+    0x13, 0x00, // event_type
+    0x01, // address_type
+    0xC1, 0xC2, 0xC3, 0xC4, 0xC5, 0xC6, // address, LSB first
+    0x01, // primary_phy
+    0x00, // secondary_phy
+    0xFF, // advertising_sid
+    0x7F, // tx_power
+    0xC4, // rssi
+    0x00, 0x00, // periodic_advertising_interval
+    0x00, // direct_address_type
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // direct_address
+    0x09, // data length
+    0x02, 0x01, 0x06, // Flags
+    0x05, 0x09, b'b', b'l', b'e', b'!', // Complete Local Name
+    // report 1
+    0x01, 0x00, // event_type
+    0x00, // address_type
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x16, // address, LSB first
+    0x03, // primary_phy
+    0x02, // secondary_phy
+    0x05, // advertising_sid
+    0xF6, // tx_power
+    0xB0, // rssi
+    0x00, 0x00, // periodic_advertising_interval
+    0x00, // direct_address_type
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // direct_address
+    0x00, // data length
+];
+
+/// LE Read Remote Features Complete
+///
+/// status = Success, connection_handle = 0x0040,
+/// le_features = LE Data Packet Length Extension, LE 2M PHY
+pub const LE_READ_REMOTE_FEATURES_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x0C, // parameter length
+    0x04, // LE Read Remote Features Page 0 Complete
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x20, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // le_features
+];
+
+/// LE Long Term Key Request
+///
+/// connection_handle = 0x0040, random_number = 0x0807060504030201,
+/// encrypted_diversifier = 0x1234
+pub const LE_LONG_TERM_KEY_REQUEST: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x0D, // parameter length
+    0x05, // LE Long Term Key Request
+    0x40, 0x00, // connection_handle
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, // random_number
+    0x34, 0x12, // encrypted_diversifier
+];
+
+/// LE Remote Connection Parameter Request
+///
+/// connection_handle = 0x0040, interval_min = 0x0006 (7.5 ms), interval_max = 0x000C (15 ms),
+/// max_latency = 0, timeout = 0x00C8 (2 s)
+pub const LE_REMOTE_CONNECTION_PARAMETER_REQUEST: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x0B, // parameter length
+    0x06, // LE Remote Connection Parameter Request
+    0x40, 0x00, // connection_handle
+    0x06, 0x00, // interval_min
+    0x0C, 0x00, // interval_max
+    0x00, 0x00, // max_latency
+    0xC8, 0x00, // timeout
+];
+
+/// LE Enhanced Connection Complete [v1] as peripheral
+///
+/// status = Success, connection_handle = 0x0040, role = Peripheral,
+/// peer_address_type = Public identity, peer_address = 06:05:04:03:02:01,
+/// local_resolvable_private_address = 56:15:14:13:12:11,
+/// peer_resolvable_private_address = 66:25:24:23:22:21, connection_interval = 0x0018 (30 ms),
+/// peripheral_latency = 0, supervision_timeout = 0x0048 (720 ms), central_clock_accuracy = 0x00
+pub const LE_ENHANCED_CONNECTION_COMPLETE_V1: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x1F, // parameter length
+    0x0A, // LE Enhanced Connection Complete [v1]
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x01, // role
+    0x02, // peer_address_type
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // peer_address, LSB first
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x56, // local_resolvable_private_address, LSB first
+    0x21, 0x22, 0x23, 0x24, 0x25, 0x66, // peer_resolvable_private_address, LSB first
+    0x18, 0x00, // connection_interval
+    0x00, 0x00, // peripheral_latency
+    0x48, 0x00, // supervision_timeout
+    0x00, // central_clock_accuracy, ignored by the peripheral
+];
+
+/// LE Enhanced Connection Complete [v2] as peripheral of a connectable periodic advertising train
+///
+/// Same connection as [`LE_ENHANCED_CONNECTION_COMPLETE_V1`], advertising_handle = 0x01,
+/// sync_handle = 0x0FFF
+pub const LE_ENHANCED_CONNECTION_COMPLETE_V2: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x22, // parameter length
+    0x29, // LE Enhanced Connection Complete [v2]
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x01, // role
+    0x02, // peer_address_type
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // peer_address, LSB first
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x56, // local_resolvable_private_address, LSB first
+    0x21, 0x22, 0x23, 0x24, 0x25, 0x66, // peer_resolvable_private_address, LSB first
+    0x18, 0x00, // connection_interval
+    0x00, 0x00, // peripheral_latency
+    0x48, 0x00, // supervision_timeout
+    0x00, // central_clock_accuracy, ignored by the peripheral
+    0x01, // advertising_handle
+    0xFF, 0x0F, // sync_handle
+];
+
+/// LE Periodic Advertising Sync Established
+///
+/// status = Success, sync_handle = 0x0001, advertising_sid = 3, advertiser_address_type = Public,
+/// advertiser_address = 11:22:33:44:55:66, advertiser_phy = LE 2M,
+/// periodic_advertising_interval = 0x0050 (100 ms), advertiser_clock_accuracy = 50 ppm
+pub const LE_PERIODIC_ADVERTISING_SYNC_ESTABLISHED: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x10, // parameter length
+    0x0E, // LE Periodic Advertising Sync Established [v1]
+    0x00, // status
+    0x01, 0x00, // sync_handle
+    0x03, // advertising_sid
+    0x00, // advertiser_address_type
+    0x66, 0x55, 0x44, 0x33, 0x22, 0x11, // advertiser_address, LSB first
+    0x02, // advertiser_phy
+    0x50, 0x00, // periodic_advertising_interval
+    0x05, // advertiser_clock_accuracy
+];
+
+/// LE Periodic Advertising Report
+///
+/// sync_handle = 0x0001, tx_power unavailable, rssi = -60, cte_type = No Constant Tone Extension,
+/// data_status = Complete, data = [Flags(0x06), CompleteLocalName("ble!")]
+pub const LE_PERIODIC_ADVERTISING_REPORT: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x11, // parameter length
+    0x0F, // LE Periodic Advertising Report [v1]
+    0x01, 0x00, // sync_handle
+    0x7F, // tx_power
+    0xC4, // rssi
+    0xFF, // cte_type
+    0x00, // data_status
+    0x09, // data length
+    0x02, 0x01, 0x06, // Flags
+    0x05, 0x09, b'b', b'l', b'e', b'!', // Complete Local Name
+];
+
+/// LE Periodic Advertising Report whose data is cut off after two of its nine bytes
+///
+/// sync_handle = 0x0001, tx_power unavailable, rssi = -60, cte_type = No Constant Tone Extension,
+/// data_status = Complete
+pub const LE_PERIODIC_ADVERTISING_REPORT_TRUNCATED_DATA: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x0A, // parameter length
+    0x0F, // LE Periodic Advertising Report [v1]
+    0x01, 0x00, // sync_handle
+    0x7F, // tx_power
+    0xC4, // rssi
+    0xFF, // cte_type
+    0x00, // data_status
+    0x09, // data length
+    0x02, 0x01, // data, truncated
+];
+
+/// LE Periodic Advertising Sync Lost
+///
+/// sync_handle = 0x0001
+pub const LE_PERIODIC_ADVERTISING_SYNC_LOST: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x03, // parameter length
+    0x10, // LE Periodic Advertising Sync Lost
+    0x01, 0x00, // sync_handle
+];
+
+/// LE Scan Timeout
+pub const LE_SCAN_TIMEOUT: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x01, // parameter length
+    0x11, // LE Scan Timeout
+];
+
+/// LE Advertising Set Terminated after a connection was created
+///
+/// status = Success, advertising_handle = 0x01, connection_handle = 0x0040,
+/// num_completed_extended_advertising_events = 5
+pub const LE_ADVERTISING_SET_TERMINATED: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x06, // parameter length
+    0x12, // LE Advertising Set Terminated
+    0x00, // status
+    0x01, // advertising_handle
+    0x40, 0x00, // connection_handle
+    0x05, // num_completed_extended_advertising_events
+];
+
+/// LE Scan Request Received
+///
+/// advertising_handle = 0x01, scanner_address_type = Random, scanner_address = C1:22:33:44:55:66
+pub const LE_SCAN_REQUEST_RECEIVED: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x09, // parameter length
+    0x13, // LE Scan Request Received
+    0x01, // advertising_handle
+    0x01, // scanner_address_type
+    0x66, 0x55, 0x44, 0x33, 0x22, 0xC1, // scanner_address, LSB first
+];
+
+/// LE Channel Selection Algorithm
+///
+/// connection_handle = 0x0040, channel_selection_algorithm = Algorithm #2
+pub const LE_CHANNEL_SELECTION_ALGORITHM: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x04, // parameter length
+    0x14, // LE Channel Selection Algorithm
+    0x40, 0x00, // connection_handle
+    0x01, // channel_selection_algorithm
+];
+
+/// LE Path Loss Threshold after the path loss rose into the high zone
+///
+/// connection_handle = 0x0040, current_path_loss = 70 dB, zone_entered = High
+pub const LE_PATH_LOSS_THRESHOLD: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x05, // parameter length
+    0x20, // LE Path Loss Threshold
+    0x40, 0x00, // connection_handle
+    0x46, // current_path_loss
+    0x02, // zone_entered
+];
+
+/// LE Transmit Power Reporting after the peer lowered its transmit power
+///
+/// status = Success, connection_handle = 0x0040, reason = Remote transmit power changed,
+/// phy = LE 2M, transmit_power_level = -12 dBm, transmit_power_level_flag = At minimum,
+/// delta = -4 dB
+pub const LE_TRANSMIT_POWER_REPORTING: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x09, // parameter length
+    0x21, // LE Transmit Power Reporting
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x01, // reason
+    0x02, // phy
+    0xF4, // transmit_power_level
+    0x01, // transmit_power_level_flag
+    0xFC, // delta
+];
+
+/// LE Subrate Change
+///
+/// status = Success, connection_handle = 0x0040, subrate_factor = 4, peripheral_latency = 1,
+/// continuation_number = 2, supervision_timeout = 0x01F4 (5 s)
+pub const LE_SUBRATE_CHANGE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x0C, // parameter length
+    0x23, // LE Subrate Change
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x04, 0x00, // subrate_factor
+    0x01, 0x00, // peripheral_latency
+    0x02, 0x00, // continuation_number
+    0xF4, 0x01, // supervision_timeout
+];
+
+/// LE Read All Remote Features Complete with two valid pages
+///
+/// status = Success, connection_handle = 0x0040, max_remote_page = 2, max_valid_page = 1,
+/// page 0 = LE Data Packet Length Extension, LE 2M PHY, page 1 = feature bit 64
+pub const LE_READ_ALL_REMOTE_FEATURES_COMPLETE: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x16, // parameter length
+    0x2B, // LE Read All Remote Features Complete
+    0x00, // status
+    0x40, 0x00, // connection_handle
+    0x02, // max_remote_page
+    0x01, // max_valid_page
+    0x20, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // le_features, page 0
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // le_features, page 1
+];
+
+/// LE Read All Remote Features Complete cut off after the connection handle
+///
+/// status = Success, connection_handle = 0x0040
+pub const LE_READ_ALL_REMOTE_FEATURES_COMPLETE_TRUNCATED: &[u8] = &[
+    0x04, // event packet
+    0x3E, // LE Meta event
+    0x04, // parameter length
+    0x2B, // LE Read All Remote Features Complete
+    0x00, // status
+    0x40, 0x00, // connection_handle
+];
+
+/// Vendor specific event, the parameters are defined by the controller manufacturer
+pub const VENDOR_SPECIFIC_EVENT: &[u8] = &[
+    0x04, // event packet
+    0xFF, // Vendor specific
+    0x03, // parameter length
+    0x01, 0xAA, 0xBB, // parameters
+];
+
+/// Every fixture above, so checks can run over all of them.
+pub const ALL: &[&[u8]] = &[
+    RESET_COMMAND_COMPLETE,
+    LE_SET_SCAN_PARAMETERS_COMMAND_COMPLETE,
+    LE_SET_ADVERTISING_ENABLE_COMMAND_DISALLOWED,
+    READ_BD_ADDR_COMMAND_COMPLETE,
+    LE_READ_BUFFER_SIZE_COMMAND_COMPLETE,
+    LE_CREATE_CONNECTION_COMMAND_STATUS,
+    DISCONNECTION_COMPLETE_REMOTE_USER,
+    DISCONNECTION_COMPLETE_TIMEOUT,
+    NUMBER_OF_COMPLETED_PACKETS,
+    NUMBER_OF_COMPLETED_PACKETS_TRAILING_BYTES,
+    ENCRYPTION_CHANGE,
+    HARDWARE_ERROR,
+    READ_REMOTE_VERSION_INFORMATION_COMPLETE,
+    DATA_BUFFER_OVERFLOW,
+    AUTHENTICATED_PAYLOAD_TIMEOUT_EXPIRED,
+    LE_CONNECTION_COMPLETE,
+    LE_CONNECTION_UPDATE_COMPLETE,
+    LE_ADVERTISING_REPORT_ADV_IND,
+    LE_ADVERTISING_REPORT_SCAN_RSP,
+    LE_ADVERTISING_REPORT_HEART_RATE,
+    LE_PHY_UPDATE_COMPLETE,
+    LE_ADVERTISING_REPORT_MULTIPLE,
+    LE_DIRECTED_ADVERTISING_REPORT,
+    LE_DIRECTED_ADVERTISING_REPORT_MULTIPLE,
+    LE_EXTENDED_ADVERTISING_REPORT,
+    LE_READ_REMOTE_FEATURES_COMPLETE,
+    LE_LONG_TERM_KEY_REQUEST,
+    LE_REMOTE_CONNECTION_PARAMETER_REQUEST,
+    LE_ENHANCED_CONNECTION_COMPLETE_V1,
+    LE_ENHANCED_CONNECTION_COMPLETE_V2,
+    LE_PERIODIC_ADVERTISING_SYNC_ESTABLISHED,
+    LE_PERIODIC_ADVERTISING_REPORT,
+    LE_PERIODIC_ADVERTISING_REPORT_TRUNCATED_DATA,
+    LE_PERIODIC_ADVERTISING_SYNC_LOST,
+    LE_SCAN_TIMEOUT,
+    LE_ADVERTISING_SET_TERMINATED,
+    LE_SCAN_REQUEST_RECEIVED,
+    LE_CHANNEL_SELECTION_ALGORITHM,
+    LE_PATH_LOSS_THRESHOLD,
+    LE_TRANSMIT_POWER_REPORTING,
+    LE_SUBRATE_CHANGE,
+    LE_READ_ALL_REMOTE_FEATURES_COMPLETE,
+    LE_READ_ALL_REMOTE_FEATURES_COMPLETE_TRUNCATED,
+    VENDOR_SPECIFIC_EVENT,
+];
+
+/// Parses a fixture as an H4 event packet.
+pub fn event_packet(fixture: &[u8]) -> HCIEventPacket<'_> {
+    match HCIPacket::from_buf(fixture) {
+        Some(HCIPacket::Event(packet)) => packet,
+        packet => panic!("fixture is not an event packet: {:?}", packet),
+    }
+}
+
+mod tests {
+    use super::*;
+    use crate::hci::{AdvertisingData, HCIEvent, LEMetaEvent};
+
+    fn assert_command_complete(
+        fixture: &[u8],
+        num_hci_command_packets: u8,
+        command_opcode: u16,
+        return_parameters: &[u8],
+    ) {
+        let packet = event_packet(fixture);
+
+        match HCIEvent::from_packet(&packet) {
+            Ok(HCIEvent::CommandComplete(event)) => {
+                assert_eq!(event.num_hci_command_packets, num_hci_command_packets);
+                assert_eq!(event.command_opcode.into_u16(), command_opcode);
+                assert_eq!(event.return_parameters, return_parameters);
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    fn disconnection_complete(fixture: &[u8]) -> (u8, u16, u8) {
+        match HCIEvent::from_packet(&event_packet(fixture)) {
+            Ok(HCIEvent::DisconnectionComplete(event)) => {
+                (event.status, event.connection_handle, event.reason)
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_command_complete_fixtures() {
+        assert_command_complete(RESET_COMMAND_COMPLETE, 1, 0x0C03, &[0x00]);
+        assert_command_complete(LE_SET_SCAN_PARAMETERS_COMMAND_COMPLETE, 1, 0x200B, &[0x00]);
+        assert_command_complete(
+            LE_SET_ADVERTISING_ENABLE_COMMAND_DISALLOWED,
+            1,
+            0x200A,
+            &[0x0C],
+        );
+        assert_command_complete(
+            READ_BD_ADDR_COMMAND_COMPLETE,
+            1,
+            0x1009,
+            &[0x00, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11],
+        );
+        assert_command_complete(
+            LE_READ_BUFFER_SIZE_COMMAND_COMPLETE,
+            1,
+            0x2002,
+            &[0x00, 0xFB, 0x00, 0x0F],
+        );
+    }
+
+    #[test]
+    fn test_disconnection_complete_fixtures() {
+        assert_eq!(
+            disconnection_complete(DISCONNECTION_COMPLETE_REMOTE_USER),
+            (0x00, 0x0040, 0x13)
+        );
+        assert_eq!(
+            disconnection_complete(DISCONNECTION_COMPLETE_TIMEOUT),
+            (0x00, 0x0041, 0x08)
+        );
+    }
+
+    #[test]
+    fn test_le_connection_complete_fixture() {
+        let packet = event_packet(LE_CONNECTION_COMPLETE);
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::ConnectionComplete(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status, 0x00);
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.role, 0x00);
+        assert_eq!(event.peer_address_type, 0x00);
+        assert_eq!(
+            event.peer_address.to_le_bytes(),
+            [0x22, 0x11, 0x00, 0xEE, 0xFF, 0xC0]
+        );
+        assert_eq!(event.connection_interval, 0x0018);
+        assert_eq!(event.peripheral_latency, 0x0000);
+        assert_eq!(event.supervision_timeout, 0x0048);
+        assert_eq!(event.central_clock_accuracy, 0x00);
+    }
+
+    #[test]
+    fn test_le_connection_update_complete_fixture() {
+        let packet = event_packet(LE_CONNECTION_UPDATE_COMPLETE);
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::ConnectionUpdateComplete(event))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.status, 0x00);
+        assert_eq!(event.connection_handle, 0x0040);
+        assert_eq!(event.connection_interval, 0x0006);
+        assert_eq!(event.peripheral_latency, 0x0000);
+        assert_eq!(event.supervision_timeout, 0x01F4);
+    }
+
+    #[test]
+    fn test_le_advertising_report_fixtures() {
+        let packet = event_packet(LE_ADVERTISING_REPORT_ADV_IND);
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::AdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        let mut report = reports.next().unwrap();
+        assert_eq!(report.event_type, 0x00);
+        assert_eq!(report.address_type, 0x01);
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x78, 0x56, 0x34, 0x12, 0x5A, 0xD6]
+        );
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::Flags(0x06))
+        ));
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::CompleteLocalName("ble!"))
+        ));
+        assert!(report.data.next().is_none());
+        assert_eq!(report.rssi, -67);
+        assert!(reports.next().is_none());
+
+        let packet = event_packet(LE_ADVERTISING_REPORT_SCAN_RSP);
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::AdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        let mut report = reports.next().unwrap();
+        assert_eq!(report.event_type, 0x04);
+        assert_eq!(
+            report.address.to_le_bytes(),
+            [0x13, 0x71, 0xDA, 0x7D, 0x1A, 0x00]
+        );
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::ShortenedLocalName("Mi"))
+        ));
+        assert!(matches!(
+            report.data.next(),
+            Some(AdvertisingData::TxPowerLevel(-8))
+        ));
+        assert!(report.data.next().is_none());
+        assert_eq!(report.rssi, -80);
+    }
+
+    #[test]
+    fn test_fixture_lengths() {
+        for fixture in ALL {
+            assert_eq!(fixture[2] as usize, fixture.len() - 3);
+            assert_eq!(event_packet(fixture).parameters, &fixture[3..]);
+        }
+    }
+
+    #[test]
+    fn test_all_fixtures_listed() {
+        let fixtures = include_str!("fixtures.rs")
+            .lines()
+            .filter(|line| line.starts_with("pub const ") && line.ends_with(": &[u8] = &["))
+            .count();
+
+        assert_eq!(ALL.len(), fixtures);
+    }
+}
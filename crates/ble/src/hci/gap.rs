@@ -1,7 +1,10 @@
 use core::mem::size_of_val;
 
-use macros::{FromU8, IntoU8};
-use utils::writer::{WriteError, Writer};
+use macros::{IntoU8, TryFromU8};
+use utils::{
+    reader::Reader,
+    writer::{WriteError, Writer},
+};
 
 pub const AD_FLAG_LIMITED_DISCOVERABLE_MODE: u8 = 0b0000_0001;
 pub const AD_FLAG_GENERAL_DISCOVERABLE_MODE: u8 = 0b0000_0010;
@@ -9,8 +12,215 @@ pub const AD_FLAG_BR_EDR_NOT_SUPPORTED: u8 = 0b0000_0100;
 pub const AD_FLAG_SIMULTANEOUS_LE_BR_EDR_CONTROLLER: u8 = 0b0000_1000;
 pub const AD_FLAG_SIMULTANEOUS_LE_BR_EDR_HOST: u8 = 0b0001_0000;
 
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.8.53
+// Advertising_Event_Properties of the LE Set Extended Advertising Parameters command, also used for
+// the Event_Type of the LE Extended Advertising Report event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdvEventProperties(u16);
+
+impl AdvEventProperties {
+    pub const CONNECTABLE: u16 = 0b0000_0000_0000_0001;
+    pub const SCANNABLE: u16 = 0b0000_0000_0000_0010;
+    pub const DIRECTED: u16 = 0b0000_0000_0000_0100;
+    pub const HIGH_DUTY_CYCLE_DIRECTED: u16 = 0b0000_0000_0000_1000;
+    pub const LEGACY: u16 = 0b0000_0000_0001_0000;
+    pub const ANONYMOUS: u16 = 0b0000_0000_0010_0000;
+    pub const INCLUDE_TX_POWER: u16 = 0b0000_0000_0100_0000;
+
+    // Only defined for the Event_Type of reports, where bit 3 marks a scan response instead of
+    // high duty cycle directed advertising and bits 5-6 hold the data status.
+    pub const SCAN_RESPONSE: u16 = 0b0000_0000_0000_1000;
+    pub const DATA_STATUS: u16 = 0b0000_0000_0110_0000;
+
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// ADV_IND
+    pub const fn legacy_connectable_scannable() -> Self {
+        Self(Self::LEGACY | Self::CONNECTABLE | Self::SCANNABLE)
+    }
+
+    /// ADV_DIRECT_IND (low duty cycle)
+    pub const fn legacy_connectable_directed() -> Self {
+        Self(Self::LEGACY | Self::CONNECTABLE | Self::DIRECTED)
+    }
+
+    /// ADV_SCAN_IND
+    pub const fn legacy_scannable() -> Self {
+        Self(Self::LEGACY | Self::SCANNABLE)
+    }
+
+    /// ADV_NONCONN_IND
+    pub const fn legacy_non_connectable() -> Self {
+        Self(Self::LEGACY)
+    }
+
+    pub const fn extended_connectable() -> Self {
+        Self(Self::CONNECTABLE)
+    }
+
+    pub const fn extended_scannable() -> Self {
+        Self(Self::SCANNABLE)
+    }
+
+    const fn with(self, bit: u16, enable: bool) -> Self {
+        match enable {
+            true => Self(self.0 | bit),
+            false => Self(self.0 & !bit),
+        }
+    }
+
+    const fn has(self, bit: u16) -> bool {
+        self.0 & bit != 0
+    }
+
+    pub const fn with_connectable(self, enable: bool) -> Self {
+        self.with(Self::CONNECTABLE, enable)
+    }
+
+    pub const fn with_scannable(self, enable: bool) -> Self {
+        self.with(Self::SCANNABLE, enable)
+    }
+
+    pub const fn with_directed(self, enable: bool) -> Self {
+        self.with(Self::DIRECTED, enable)
+    }
+
+    pub const fn with_high_duty_cycle_directed(self, enable: bool) -> Self {
+        self.with(Self::HIGH_DUTY_CYCLE_DIRECTED, enable)
+    }
+
+    pub const fn with_legacy(self, enable: bool) -> Self {
+        self.with(Self::LEGACY, enable)
+    }
+
+    pub const fn with_anonymous(self, enable: bool) -> Self {
+        self.with(Self::ANONYMOUS, enable)
+    }
+
+    pub const fn with_include_tx_power(self, enable: bool) -> Self {
+        self.with(Self::INCLUDE_TX_POWER, enable)
+    }
+
+    pub const fn is_connectable(self) -> bool {
+        self.has(Self::CONNECTABLE)
+    }
+
+    pub const fn is_scannable(self) -> bool {
+        self.has(Self::SCANNABLE)
+    }
+
+    pub const fn is_directed(self) -> bool {
+        self.has(Self::DIRECTED)
+    }
+
+    pub const fn is_high_duty_cycle_directed(self) -> bool {
+        self.has(Self::HIGH_DUTY_CYCLE_DIRECTED)
+    }
+
+    pub const fn is_legacy(self) -> bool {
+        self.has(Self::LEGACY)
+    }
+
+    pub const fn is_anonymous(self) -> bool {
+        self.has(Self::ANONYMOUS)
+    }
+
+    pub const fn includes_tx_power(self) -> bool {
+        self.has(Self::INCLUDE_TX_POWER)
+    }
+
+    pub const fn is_scan_response(self) -> bool {
+        self.has(Self::SCAN_RESPONSE)
+    }
+
+    pub fn data_status(self) -> Result<AdvDataStatus, u8> {
+        AdvDataStatus::try_from(
+            ((self.0 & Self::DATA_STATUS) >> Self::DATA_STATUS.trailing_zeros()) as u8,
+        )
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.13
+// Data status bits of the Event_Type of the LE Extended Advertising Report event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoU8, TryFromU8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AdvDataStatus {
+    Complete = 0x00,
+    Incomplete = 0x01,
+    Truncated = 0x02,
+}
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part B, Section 2.5.1
+// UUIDs are 128-bit values, 16 and 32-bit UUIDs are shortened forms of UUIDs based on the Bluetooth Base UUID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Uuid {
+    Uuid16(u16),
+    Uuid32(u32),
+    Uuid128(u128),
+}
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part B, Section 2.5.1
+// 00000000-0000-1000-8000-00805F9B34FB
+const BLUETOOTH_BASE_UUID: u128 = 0x00000000_0000_1000_8000_00805F9B34FB;
+
+impl Uuid {
+    /// Returns the full 128-bit UUID, expanding 16 and 32-bit UUIDs with the Bluetooth Base UUID.
+    pub const fn to_u128(self) -> u128 {
+        match self {
+            Uuid::Uuid16(uuid) => ((uuid as u128) << 96) | BLUETOOTH_BASE_UUID,
+            Uuid::Uuid32(uuid) => ((uuid as u128) << 96) | BLUETOOTH_BASE_UUID,
+            Uuid::Uuid128(uuid) => uuid,
+        }
+    }
+}
+
+/// Reads UUIDs from a [`Reader`], least significant octet first as they are transmitted.
+///
+/// A 128-bit UUID is therefore sent in the reverse of its string notation, the Bluetooth Base UUID
+/// starts with 0xFB on the wire.
+pub trait ReadUuid {
+    fn read_uuid16(&mut self) -> Option<Uuid>;
+
+    fn read_uuid32(&mut self) -> Option<Uuid>;
+
+    fn read_uuid128(&mut self) -> Option<Uuid>;
+}
+
+impl ReadUuid for Reader<'_> {
+    fn read_uuid16(&mut self) -> Option<Uuid> {
+        self.read_u16().map(Uuid::Uuid16)
+    }
+
+    fn read_uuid32(&mut self) -> Option<Uuid> {
+        self.read_u32().map(Uuid::Uuid32)
+    }
+
+    fn read_uuid128(&mut self) -> Option<Uuid> {
+        self.read_u128().map(Uuid::Uuid128)
+    }
+}
+
+// Bluetooth Core Supplement spec | Part A, Section 1.9
+pub(crate) const NO_SPECIFIC_CONNECTION_INTERVAL: u16 = 0xFFFF;
+
 // Bluetooth Assigned Numbers | Section 2.3 | page 12
-#[derive(Debug, IntoU8, FromU8)]
+#[derive(Debug, IntoU8, TryFromU8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum AdvertisingDataType {
     Flags = 0x01,                              // Flags
     IncompleteListOf16BitServiceUUIDs = 0x02,  // Incomplete List of 16-bit Service UUIDs
@@ -31,7 +241,8 @@ pub enum AdvertisingDataType {
 }
 
 // Bluetooth Core Supplement spec | Part A, Section 1 | page 9
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum AdvertisingData<'p> {
     /// Bluetooth Core Supplement Spec | Part A, Section 1.3 | page 12
     ///
@@ -46,17 +257,20 @@ pub enum AdvertisingData<'p> {
     Flags(u8),
 
     /// Bluetooth Core Supplement Spec | Part A, Section 1.1 | Page 10
-    IncompleteListOf16BitServiceUUIDs(&'p [u16]),
+    ///
+    /// The service UUID lists hold the UUIDs as they are transmitted, least significant octet
+    /// first, use [`Self::service_uuids`] to decode them.
+    IncompleteListOf16BitServiceUUIDs(&'p [u8]),
     /// Bluetooth Core Supplement Spec | Part A, Section 1.1 | Page 10
-    CompleteListOf16BitServiceUUIDs(&'p [u16]),
+    CompleteListOf16BitServiceUUIDs(&'p [u8]),
     /// Bluetooth Core Supplement Spec | Part A, Section 1.1 | Page 10
-    IncompleteListOf32BitServiceUUIDs(&'p [u32]),
+    IncompleteListOf32BitServiceUUIDs(&'p [u8]),
     /// Bluetooth Core Supplement Spec | Part A, Section 1.1 | Page 10
-    CompleteListOf32BitServiceUUIDs(&'p [u32]),
+    CompleteListOf32BitServiceUUIDs(&'p [u8]),
     /// Bluetooth Core Supplement Spec | Part A, Section 1.1 | Page 10
-    IncompleteListOf128BitServiceUUIDs(&'p [u128]),
+    IncompleteListOf128BitServiceUUIDs(&'p [u8]),
     /// Bluetooth Core Supplement Spec | Part A, Section 1.1 | Page 10
-    CompleteListOf128BitServiceUUIDs(&'p [u128]),
+    CompleteListOf128BitServiceUUIDs(&'p [u8]),
     /// Bluetooth Core Supplement Spec | Part A, Section 1.2 | Page 11
     ///
     /// Bluetooth Core Spec | [Vol 4] Part E, Section 6.23 | Page 1891
@@ -74,7 +288,12 @@ pub enum AdvertisingData<'p> {
     /// Bluetooth Assigned Numbers | Section 2.8 | page 45
     ClassOfDevice(u32),
     /// Bluetooth Core Supplement Spec | Part A, Section 1.9 | Page 16
-    PeripheralConnectionIntervalRange(&'p [u8]),
+    ///
+    /// Both values are in 1.25 ms units, `None` means no specific minimum or maximum (0xFFFF).
+    PeripheralConnectionIntervalRange {
+        interval_min: Option<u16>,
+        interval_max: Option<u16>,
+    },
     /// Bluetooth Core Supplement Spec | Part A, Section 1.11 | Page 18
     ServiceData(&'p [u8]),
     ///  Bluetooth Core Supplement Spec | Section 1.12 | page 18
@@ -82,55 +301,97 @@ pub enum AdvertisingData<'p> {
     /// Bluetooth Core Supplement Spec | Part A, Section 1.16 | Page 20
     LEBluetoothDeviceAddress(&'p [u8]),
     /// Bluetooth Core Supplement Spec | Part A, Section 1.14 | Page 13
-    ManufacturerSpecificData(&'p [u8]),
+    ///
+    /// Elements too short to contain the company identifier are returned as [`Self::Unknown`].
+    ManufacturerSpecificData {
+        company_identifier: u16, // Bluetooth Assigned Numbers, Section 7.1
+        data: &'p [u8],
+    },
+    /// An AD type not covered by [`AdvertisingDataType`], or a known type whose value is malformed,
+    /// kept so iteration can continue past it.
+    Unknown { ad_type: u8, data: &'p [u8] },
 }
 
 impl<'p> AdvertisingData<'p> {
-    pub fn write_into(&self, buf: &'p mut [u8]) -> Result<usize, WriteError> {
+    /// Returns the UUIDs of the service UUID list variants, the iterator is empty for any other
+    /// variant.
+    pub fn service_uuids(&self) -> ServiceUuidIterator<'p> {
+        match *self {
+            AdvertisingData::IncompleteListOf16BitServiceUUIDs(uuids)
+            | AdvertisingData::CompleteListOf16BitServiceUUIDs(uuids) => {
+                ServiceUuidIterator::new(uuids, UuidSize::Uuid16)
+            }
+            AdvertisingData::IncompleteListOf32BitServiceUUIDs(uuids)
+            | AdvertisingData::CompleteListOf32BitServiceUUIDs(uuids) => {
+                ServiceUuidIterator::new(uuids, UuidSize::Uuid32)
+            }
+            AdvertisingData::IncompleteListOf128BitServiceUUIDs(uuids)
+            | AdvertisingData::CompleteListOf128BitServiceUUIDs(uuids) => {
+                ServiceUuidIterator::new(uuids, UuidSize::Uuid128)
+            }
+            _ => ServiceUuidIterator::new(&[], UuidSize::Uuid16),
+        }
+    }
+
+    pub fn write_into(&self, buf: &mut [u8]) -> Result<usize, WriteError> {
         let mut writer = Writer::new(buf);
+        self.write(&mut writer)?;
+
+        Ok(writer.pos)
+    }
+
+    /// Writes the length, type and value of the element, the inverse of
+    /// [`AdvertisingDataIterator`](super::AdvertisingDataIterator).
+    pub fn write(&self, writer: &mut Writer) -> Result<(), WriteError> {
         match *self {
             AdvertisingData::Flags(flags) => {
                 writer.write_u8((2 * size_of::<u8>()) as u8)?;
                 writer.write_u8(AdvertisingDataType::Flags as u8)?;
                 writer.write_u8(flags)?;
             }
-            AdvertisingData::IncompleteListOf16BitServiceUUIDs(uuids) => {
-                writer.write_u8((size_of_val(uuids) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::IncompleteListOf16BitServiceUUIDs as u8)?;
-                writer.write_u16_slice(uuids)?;
-            }
-            AdvertisingData::CompleteListOf16BitServiceUUIDs(uuids) => {
-                writer.write_u8((size_of_val(uuids) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::CompleteListOf16BitServiceUUIDs as u8)?;
-                writer.write_u16_slice(uuids)?;
-            }
-            AdvertisingData::IncompleteListOf32BitServiceUUIDs(uuids) => {
-                writer.write_u8((size_of_val(uuids) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::IncompleteListOf32BitServiceUUIDs as u8)?;
-                writer.write_u32_slice(uuids)?;
-            }
-            AdvertisingData::CompleteListOf32BitServiceUUIDs(uuids) => {
-                writer.write_u8((size_of_val(uuids) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::CompleteListOf32BitServiceUUIDs as u8)?;
-                writer.write_u32_slice(uuids)?;
-            }
-            AdvertisingData::IncompleteListOf128BitServiceUUIDs(uuids) => {
-                writer.write_u8((size_of_val(uuids) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::IncompleteListOf128BitServiceUUIDs as u8)?;
-                writer.write_u128_slice(uuids)?;
-            }
-            AdvertisingData::CompleteListOf128BitServiceUUIDs(uuids) => {
-                writer.write_u8((size_of_val(uuids) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::CompleteListOf128BitServiceUUIDs as u8)?;
-                writer.write_u128_slice(uuids)?;
-            }
+            AdvertisingData::IncompleteListOf16BitServiceUUIDs(uuids) => write_service_uuids(
+                writer,
+                AdvertisingDataType::IncompleteListOf16BitServiceUUIDs,
+                uuids,
+                UuidSize::Uuid16,
+            )?,
+            AdvertisingData::CompleteListOf16BitServiceUUIDs(uuids) => write_service_uuids(
+                writer,
+                AdvertisingDataType::CompleteListOf16BitServiceUUIDs,
+                uuids,
+                UuidSize::Uuid16,
+            )?,
+            AdvertisingData::IncompleteListOf32BitServiceUUIDs(uuids) => write_service_uuids(
+                writer,
+                AdvertisingDataType::IncompleteListOf32BitServiceUUIDs,
+                uuids,
+                UuidSize::Uuid32,
+            )?,
+            AdvertisingData::CompleteListOf32BitServiceUUIDs(uuids) => write_service_uuids(
+                writer,
+                AdvertisingDataType::CompleteListOf32BitServiceUUIDs,
+                uuids,
+                UuidSize::Uuid32,
+            )?,
+            AdvertisingData::IncompleteListOf128BitServiceUUIDs(uuids) => write_service_uuids(
+                writer,
+                AdvertisingDataType::IncompleteListOf128BitServiceUUIDs,
+                uuids,
+                UuidSize::Uuid128,
+            )?,
+            AdvertisingData::CompleteListOf128BitServiceUUIDs(uuids) => write_service_uuids(
+                writer,
+                AdvertisingDataType::CompleteListOf128BitServiceUUIDs,
+                uuids,
+                UuidSize::Uuid128,
+            )?,
             AdvertisingData::ShortenedLocalName(name) => {
-                writer.write_u8((size_of_val(name) + size_of::<u8>()) as u8)?;
+                write_length(writer, size_of_val(name))?;
                 writer.write_u8(AdvertisingDataType::ShortenedLocalName as u8)?;
                 writer.write_u8_slice(name.as_bytes())?;
             }
             AdvertisingData::CompleteLocalName(name) => {
-                writer.write_u8((size_of_val(name) + size_of::<u8>()) as u8)?;
+                write_length(writer, size_of_val(name))?;
                 writer.write_u8(AdvertisingDataType::CompleteLocalName as u8)?;
                 writer.write_u8_slice(name.as_bytes())?;
             }
@@ -144,33 +405,380 @@ impl<'p> AdvertisingData<'p> {
                 writer.write_u8(AdvertisingDataType::ClassOfDevice as u8)?;
                 writer.write_u32(class)?;
             }
-            AdvertisingData::PeripheralConnectionIntervalRange(range) => {
-                writer.write_u8((size_of_val(range) + size_of::<u8>()) as u8)?;
+            AdvertisingData::PeripheralConnectionIntervalRange {
+                interval_min,
+                interval_max,
+            } => {
+                writer.write_u8((2 * size_of::<u16>() + size_of::<u8>()) as u8)?;
                 writer.write_u8(AdvertisingDataType::PeripheralConnectionIntervalRange as u8)?;
-                writer.write_u8_slice(range)?;
+                writer.write_u16(interval_min.unwrap_or(NO_SPECIFIC_CONNECTION_INTERVAL))?;
+                writer.write_u16(interval_max.unwrap_or(NO_SPECIFIC_CONNECTION_INTERVAL))?;
             }
             AdvertisingData::ServiceData(data) => {
-                writer.write_u8((size_of_val(data) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::PeripheralConnectionIntervalRange as u8)?;
+                write_length(writer, size_of_val(data))?;
+                writer.write_u8(AdvertisingDataType::ServiceData as u8)?;
                 writer.write_u8_slice(data)?;
             }
             AdvertisingData::Appearance(appearance) => {
                 writer.write_u8((size_of::<u16>() + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::PeripheralConnectionIntervalRange as u8)?;
+                writer.write_u8(AdvertisingDataType::Appearance as u8)?;
                 writer.write_u16(appearance)?;
             }
             AdvertisingData::LEBluetoothDeviceAddress(address) => {
-                writer.write_u8((size_of_val(address) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::PeripheralConnectionIntervalRange as u8)?;
+                write_length(writer, size_of_val(address))?;
+                writer.write_u8(AdvertisingDataType::LEBluetoothDeviceAddress as u8)?;
                 writer.write_u8_slice(address)?;
             }
-            AdvertisingData::ManufacturerSpecificData(data) => {
-                writer.write_u8((size_of_val(data) + size_of::<u8>()) as u8)?;
-                writer.write_u8(AdvertisingDataType::PeripheralConnectionIntervalRange as u8)?;
+            AdvertisingData::ManufacturerSpecificData {
+                company_identifier,
+                data,
+            } => {
+                write_length(writer, size_of::<u16>() + size_of_val(data))?;
+                writer.write_u8(AdvertisingDataType::ManufacturerSpecificData as u8)?;
+                writer.write_u16(company_identifier)?;
+                writer.write_u8_slice(data)?;
+            }
+            AdvertisingData::Unknown { ad_type, data } => {
+                write_length(writer, size_of_val(data))?;
+                writer.write_u8(ad_type)?;
                 writer.write_u8_slice(data)?;
             }
         };
 
-        Ok(writer.pos)
+        Ok(())
+    }
+}
+
+// Writes the length of an element with a value of `len` bytes, the length covers the type as well
+// so the value can't be longer than 254 bytes
+fn write_length(writer: &mut Writer, len: usize) -> Result<(), WriteError> {
+    let len = u8::try_from(len + size_of::<u8>()).map_err(|_| WriteError::InvalidFormat)?;
+
+    writer.write_u8(len)
+}
+
+// Writes every UUID least significant octet first, a list that doesn't hold a whole number of
+// UUIDs is rejected
+fn write_service_uuids(
+    writer: &mut Writer,
+    ad_type: AdvertisingDataType,
+    uuids: &[u8],
+    size: UuidSize,
+) -> Result<(), WriteError> {
+    if !uuids.len().is_multiple_of(size.len()) {
+        return Err(WriteError::InvalidFormat);
+    }
+
+    write_length(writer, size_of_val(uuids))?;
+    writer.write_u8(ad_type as u8)?;
+
+    for uuid in ServiceUuidIterator::new(uuids, size) {
+        match uuid {
+            Uuid::Uuid16(uuid) => writer.write_u16(uuid)?,
+            Uuid::Uuid32(uuid) => writer.write_u32(uuid)?,
+            Uuid::Uuid128(uuid) => writer.write_u128(uuid)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes the UUIDs of a service UUID list, see [`AdvertisingData::service_uuids`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ServiceUuidIterator<'p> {
+    reader: Reader<'p>,
+    size: UuidSize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) enum UuidSize {
+    Uuid16,
+    Uuid32,
+    Uuid128,
+}
+
+impl UuidSize {
+    pub(crate) const fn len(self) -> usize {
+        match self {
+            UuidSize::Uuid16 => size_of::<u16>(),
+            UuidSize::Uuid32 => size_of::<u32>(),
+            UuidSize::Uuid128 => size_of::<u128>(),
+        }
+    }
+}
+
+impl<'p> ServiceUuidIterator<'p> {
+    pub(crate) fn new(uuids: &'p [u8], size: UuidSize) -> Self {
+        Self {
+            reader: Reader::new(uuids),
+            size,
+        }
+    }
+}
+
+impl Iterator for ServiceUuidIterator<'_> {
+    type Item = Uuid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.size {
+            UuidSize::Uuid16 => self.reader.read_uuid16(),
+            UuidSize::Uuid32 => self.reader.read_uuid32(),
+            UuidSize::Uuid128 => self.reader.read_uuid128(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hci::AdvertisingDataIterator;
+
+    #[test]
+    fn test_read_uuid16() {
+        let mut reader = Reader::new(&[0x0D, 0x18, 0x0F]);
+
+        assert_eq!(reader.read_uuid16(), Some(Uuid::Uuid16(0x180D)));
+        assert_eq!(reader.read_uuid16(), None);
+        assert_eq!(reader.remaining(), 1);
+    }
+
+    #[test]
+    fn test_read_uuid32() {
+        let mut reader = Reader::new(&[0x0D, 0x18, 0x00, 0x00, 0x0F]);
+
+        assert_eq!(reader.read_uuid32(), Some(Uuid::Uuid32(0x0000180D)));
+        assert_eq!(reader.read_uuid32(), None);
+    }
+
+    #[test]
+    fn test_read_uuid128() {
+        // 0000180D-0000-1000-8000-00805F9B34FB, least significant octet first
+        let buf = [
+            0xFB, 0x34, 0x9B, 0x5F, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00, 0x0D, 0x18,
+            0x00, 0x00,
+        ];
+        let mut reader = Reader::new(&buf);
+
+        let uuid = reader.read_uuid128().unwrap();
+
+        assert_eq!(uuid, Uuid::Uuid128(0x0000180D_0000_1000_8000_00805F9B34FB));
+        assert_eq!(uuid.to_u128(), Uuid::Uuid16(0x180D).to_u128());
+        assert_eq!(reader.read_uuid128(), None);
+    }
+
+    #[test]
+    fn test_legacy_connectable_scannable_properties() {
+        let properties = AdvEventProperties::legacy_connectable_scannable();
+
+        assert_eq!(properties.bits(), 0b0001_0011);
+        assert!(properties.is_legacy());
+        assert!(properties.is_connectable());
+        assert!(properties.is_scannable());
+        assert!(!properties.is_directed());
+        assert_eq!(
+            properties,
+            AdvEventProperties::new()
+                .with_legacy(true)
+                .with_connectable(true)
+                .with_scannable(true)
+        );
+    }
+
+    #[test]
+    fn test_extended_connectable_non_scannable_properties() {
+        let properties = AdvEventProperties::extended_connectable().with_include_tx_power(true);
+
+        assert_eq!(properties.bits(), 0b0100_0001);
+        assert!(properties.is_connectable());
+        assert!(!properties.is_scannable());
+        assert!(!properties.is_legacy());
+        assert!(properties.includes_tx_power());
+        assert!(!properties.with_connectable(false).is_connectable());
+    }
+
+    #[test]
+    fn test_report_event_type_properties() {
+        // Legacy SCAN_RSP to an ADV_IND
+        let properties = AdvEventProperties::from_bits(0b0001_1011);
+
+        assert!(properties.is_legacy());
+        assert!(properties.is_scan_response());
+        assert!(properties.is_connectable());
+        assert_eq!(properties.data_status(), Ok(AdvDataStatus::Complete));
+
+        // Extended, more data to come
+        let properties = AdvEventProperties::from_bits(0b0010_0000);
+
+        assert!(!properties.is_scan_response());
+        assert_eq!(properties.data_status(), Ok(AdvDataStatus::Incomplete));
+        assert_eq!(
+            AdvEventProperties::from_bits(0b0100_0000).data_status(),
+            Ok(AdvDataStatus::Truncated)
+        );
+        assert_eq!(
+            AdvEventProperties::from_bits(0b0110_0000).data_status(),
+            Err(0b11)
+        );
+    }
+
+    #[test]
+    fn test_uuid16_to_u128() {
+        // Heart Rate service
+        assert_eq!(
+            Uuid::Uuid16(0x180D).to_u128(),
+            0x0000180D_0000_1000_8000_00805F9B34FB
+        );
+        assert_eq!(
+            Uuid::Uuid32(0x0000180D).to_u128(),
+            Uuid::Uuid16(0x180D).to_u128()
+        );
+        assert_eq!(
+            Uuid::Uuid128(0x0000180D_0000_1000_8000_00805F9B34FB).to_u128(),
+            Uuid::Uuid16(0x180D).to_u128()
+        );
+    }
+
+    #[test]
+    fn test_service_uuids() {
+        let data = AdvertisingData::CompleteListOf16BitServiceUUIDs(&[0x0D, 0x18, 0x0F, 0x18]);
+        let mut uuids = data.service_uuids();
+
+        assert_eq!(uuids.next(), Some(Uuid::Uuid16(0x180D)));
+        assert_eq!(uuids.next(), Some(Uuid::Uuid16(0x180F)));
+        assert_eq!(uuids.next(), None);
+
+        let data = AdvertisingData::IncompleteListOf128BitServiceUUIDs(
+            &0x0000180D_0000_1000_8000_00805F9B34FB_u128.to_le_bytes(),
+        );
+        assert!(
+            data.service_uuids()
+                .map(Uuid::to_u128)
+                .eq([Uuid::Uuid16(0x180D).to_u128()])
+        );
+
+        assert_eq!(AdvertisingData::Flags(0x06).service_uuids().next(), None);
+    }
+
+    #[test]
+    fn test_write_flags_and_name() {
+        let mut buf = [0; 31];
+        let mut writer = Writer::new(&mut buf);
+
+        AdvertisingData::Flags(AD_FLAG_GENERAL_DISCOVERABLE_MODE | AD_FLAG_BR_EDR_NOT_SUPPORTED)
+            .write(&mut writer)
+            .unwrap();
+        AdvertisingData::CompleteLocalName("ble")
+            .write(&mut writer)
+            .unwrap();
+
+        let len = writer.pos;
+        assert_eq!(
+            &buf[..len],
+            &[0x02, 0x01, 0x06, 0x04, 0x09, b'b', b'l', b'e']
+        );
+    }
+
+    #[test]
+    fn test_write_round_trip() {
+        let elements = [
+            AdvertisingData::Flags(AD_FLAG_LIMITED_DISCOVERABLE_MODE),
+            AdvertisingData::IncompleteListOf16BitServiceUUIDs(&[0x0D, 0x18, 0x0F, 0x18]),
+            AdvertisingData::CompleteListOf16BitServiceUUIDs(&[0x0A, 0x18]),
+            AdvertisingData::IncompleteListOf32BitServiceUUIDs(&[0x0D, 0x18, 0x00, 0x00]),
+            AdvertisingData::CompleteListOf32BitServiceUUIDs(&[0x0F, 0x18, 0x00, 0x00]),
+            AdvertisingData::IncompleteListOf128BitServiceUUIDs(
+                &0x0000180D_0000_1000_8000_00805F9B34FB_u128.to_le_bytes(),
+            ),
+            AdvertisingData::CompleteListOf128BitServiceUUIDs(
+                &0x0000180F_0000_1000_8000_00805F9B34FB_u128.to_le_bytes(),
+            ),
+            AdvertisingData::ShortenedLocalName("bl"),
+            AdvertisingData::CompleteLocalName("ble"),
+            AdvertisingData::TxPowerLevel(-8),
+            AdvertisingData::PeripheralConnectionIntervalRange {
+                interval_min: Some(0x0006),
+                interval_max: None,
+            },
+            AdvertisingData::ServiceData(&[0x0D, 0x18, 0x01]),
+            AdvertisingData::Appearance(0x0341),
+            AdvertisingData::LEBluetoothDeviceAddress(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00]),
+            AdvertisingData::ManufacturerSpecificData {
+                company_identifier: 0x0059,
+                data: &[0xAA],
+            },
+            AdvertisingData::Unknown {
+                ad_type: 0x3D,
+                data: &[0x01],
+            },
+        ];
+        let mut buf = [0; 128];
+        let mut writer = Writer::new(&mut buf);
+
+        for element in &elements {
+            element.write(&mut writer).unwrap();
+        }
+
+        let len = writer.pos;
+        let parsed = AdvertisingDataIterator::new(Reader::new(&buf[..len]));
+        assert!(parsed.eq(elements));
+    }
+
+    #[test]
+    fn test_write_value_too_long() {
+        let data = [0xAA; 256];
+        let mut buf = [0; 512];
+
+        assert!(matches!(
+            AdvertisingData::ServiceData(&data[..255]).write_into(&mut buf),
+            Err(WriteError::InvalidFormat)
+        ));
+        assert!(matches!(
+            AdvertisingData::ManufacturerSpecificData {
+                company_identifier: 0x0059,
+                data: &data[..253],
+            }
+            .write_into(&mut buf),
+            Err(WriteError::InvalidFormat)
+        ));
+        assert!(matches!(
+            AdvertisingData::CompleteListOf128BitServiceUUIDs(&data).write_into(&mut buf),
+            Err(WriteError::InvalidFormat)
+        ));
+        assert!(matches!(
+            AdvertisingData::Unknown {
+                ad_type: 0xFF,
+                data: &data[..254],
+            }
+            .write_into(&mut buf),
+            Ok(256)
+        ));
+        assert_eq!(buf[0], 0xFF);
+    }
+
+    #[test]
+    fn test_write_service_uuids_little_endian() {
+        let mut buf = [0; 31];
+        let data = AdvertisingData::CompleteListOf16BitServiceUUIDs(&[0x0D, 0x18, 0x0F, 0x18]);
+
+        let len = data.write_into(&mut buf).unwrap();
+
+        assert_eq!(&buf[..len], &[0x05, 0x03, 0x0D, 0x18, 0x0F, 0x18]);
+        assert!(matches!(
+            AdvertisingData::CompleteListOf16BitServiceUUIDs(&[0x0D, 0x18, 0x0F])
+                .write_into(&mut buf),
+            Err(WriteError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn test_write_buffer_overflow() {
+        let mut buf = [0; 4];
+
+        assert!(matches!(
+            AdvertisingData::CompleteLocalName("ble").write_into(&mut buf),
+            Err(WriteError::BufferOverflow)
+        ));
     }
 }
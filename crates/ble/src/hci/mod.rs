@@ -1,9 +1,32 @@
+mod acl;
+mod address;
 mod command;
+mod command_return;
+pub mod commands;
+mod connection;
+mod error_code;
 mod event;
+#[cfg(test)]
+pub(crate) mod fixtures;
 mod gap;
+mod opcode;
+#[cfg(feature = "heapless")]
+mod owned;
 mod packet;
+#[cfg(feature = "alloc")]
+mod scan;
 
+pub use acl::*;
+pub use address::*;
 pub use command::*;
+pub use command_return::*;
+pub use connection::*;
+pub use error_code::*;
 pub use event::*;
 pub use gap::*;
+pub use opcode::*;
+#[cfg(feature = "heapless")]
+pub use owned::*;
 pub use packet::*;
+#[cfg(feature = "alloc")]
+pub use scan::*;
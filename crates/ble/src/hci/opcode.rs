@@ -0,0 +1,59 @@
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.1 | page 1872
+// [...] Each command is assigned a 2 byte Opcode used to uniquely identify different types of commands.
+// The Opcode parameter is divided into two fields, called the Opcode Group Field (OGF) and Opcode Command Field (OCF).
+// The OGF occupies the upper 6 bits of the Opcode, while the OCF occupies the remaining 10 bits. [...]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Opcode(u16);
+
+impl Opcode {
+    const OGF_MASK: u16 = 0b1111_1100_0000_0000;
+    const OCF_MASK: u16 = 0b0000_0011_1111_1111;
+
+    pub const fn new(ogf: u8, ocf: u16) -> Self {
+        Self((((ogf as u16) << 10) & Self::OGF_MASK) | (ocf & Self::OCF_MASK))
+    }
+
+    pub const fn from_u16(value: u16) -> Self {
+        Self(value)
+    }
+
+    pub const fn into_u16(self) -> u16 {
+        self.0
+    }
+
+    pub const fn ogf(&self) -> u8 {
+        ((self.0 & Self::OGF_MASK) >> 10) as u8
+    }
+
+    pub const fn ocf(&self) -> u16 {
+        self.0 & Self::OCF_MASK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opcode_layout() {
+        assert_eq!(Opcode::new(0x03, 0x0003).into_u16(), 0x0C03);
+        assert_eq!(Opcode::new(0x08, 0x000C).into_u16(), 0x200C);
+        assert_eq!(Opcode::new(0x3F, 0x03FF).into_u16(), 0xFFFF);
+    }
+
+    #[test]
+    fn test_opcode_round_trip() {
+        for value in [0x0000, 0x0C03, 0x200D, 0xFC01, 0xFFFF] {
+            let opcode = Opcode::from_u16(value);
+
+            assert_eq!(Opcode::new(opcode.ogf(), opcode.ocf()), opcode);
+            assert_eq!(opcode.into_u16(), value);
+        }
+
+        let opcode = Opcode::from_u16(0x200D);
+        assert_eq!(opcode.ogf(), 0x08);
+        assert_eq!(opcode.ocf(), 0x000D);
+    }
+}
@@ -0,0 +1,513 @@
+use heapless::Vec;
+use utils::reader::Reader;
+
+use super::{
+    AdvEventProperties, AdvertisingDataIterator, AdvertisingReport, AdvertisingReportIterator,
+    AdvertisingSetTerminatedEvent, AuthenticatedPayloadTimeoutExpiredEvent,
+    ChannelSelectionAlgorithmEvent, CommandCompleteEvent, CommandStatusEvent,
+    ConnectionCompleteEvent, ConnectionUpdateCompleteEvent, DataBufferOverflowEvent,
+    DirectedAdvertisingReportIterator, DisconnectionCompleteEvent, EncryptionChangeEvent,
+    EnhancedConnectionCompleteEvent, ExtendedAdvertisingReport, ExtendedAdvertisingReportIterator,
+    HCIEvent, HardwareErrorEvent, LEMetaEvent, LongTermKeyRequestEvent,
+    NumberOfCompletedPacketsEvent, Opcode, PHYUpdateCompleteEvent, PathLossThresholdEvent,
+    PeriodicAdvertisingSyncEstablishedEvent, PeriodicAdvertisingSyncLostEvent,
+    ReadRemoteFeaturesCompleteEvent, ReadRemoteVersionInformationCompleteEvent,
+    RemoteConnectionParameterRequestEvent, ScanRequestReceivedEvent, SubrateChangeEvent,
+    TransmitPowerReportingEvent, address::BdAddr,
+};
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.4
+// Event parameters are at most 255 bytes, the capacities below subtract the fields in front of the
+// copied bytes.
+const MAX_EVENT_PARAMETERS_LEN: usize = 255;
+const MAX_RETURN_PARAMETERS_LEN: usize = 252;
+const MAX_COMPLETED_PACKETS_LEN: usize = 254;
+const MAX_SUBEVENT_PARAMETERS_LEN: usize = 254;
+const MAX_REPORTS_LEN: usize = 253;
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.2
+const MAX_ADVERTISING_DATA_LEN: usize = 31;
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.13
+const MAX_EXTENDED_ADVERTISING_DATA_LEN: usize = 229;
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.38
+const MAX_FEATURE_PAGES_LEN: usize = 248;
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 7.7.65.15
+const MAX_PERIODIC_ADVERTISING_DATA_LEN: usize = 247;
+
+/// A copy of an [`HCIEvent`] that does not borrow from the packet buffer, so it can be queued and
+/// processed after the buffer has been reused.
+///
+/// Slices are copied into fixed capacity [`heapless::Vec`]s. Events carrying a list of entries keep
+/// the raw bytes of the list and hand out the same iterators as the borrowed event.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OwnedHCIEvent {
+    DisconnectionComplete(DisconnectionCompleteEvent),
+    EncryptionChange(EncryptionChangeEvent),
+    ReadRemoteVersionInformationComplete(ReadRemoteVersionInformationCompleteEvent),
+    CommandComplete(OwnedCommandCompleteEvent),
+    CommandStatus(CommandStatusEvent),
+    HardwareError(HardwareErrorEvent),
+    NumberOfCompletedPackets(OwnedNumberOfCompletedPacketsEvent),
+    DataBufferOverflow(DataBufferOverflowEvent),
+    LEMetaEvent(OwnedLEMetaEvent),
+    AuthenticatedPayloadTimeoutExpired(AuthenticatedPayloadTimeoutExpiredEvent),
+    Unknown {
+        evcode: u8,
+        parameters: Vec<u8, MAX_EVENT_PARAMETERS_LEN>,
+    },
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OwnedLEMetaEvent {
+    ConnectionComplete(ConnectionCompleteEvent),
+    AdvertisingReport(OwnedAdvertisingReports),
+    ConnectionUpdateComplete(ConnectionUpdateCompleteEvent),
+    ReadRemoteFeaturesComplete(ReadRemoteFeaturesCompleteEvent),
+    LongTermKeyRequest(LongTermKeyRequestEvent),
+    RemoteConnectionParameterRequest(RemoteConnectionParameterRequestEvent),
+    EnhancedConnectionComplete(EnhancedConnectionCompleteEvent),
+    DirectedAdvertisingReport(OwnedDirectedAdvertisingReports),
+    PHYUpdateComplete(PHYUpdateCompleteEvent),
+    ExtendedAdvertisingReport(OwnedExtendedAdvertisingReports),
+    PeriodicAdvertisingSyncEstablished(PeriodicAdvertisingSyncEstablishedEvent),
+    PeriodicAdvertisingReport(OwnedPeriodicAdvertisingReportEvent),
+    PeriodicAdvertisingSyncLost(PeriodicAdvertisingSyncLostEvent),
+    ScanTimeout,
+    AdvertisingSetTerminated(AdvertisingSetTerminatedEvent),
+    ScanRequestReceived(ScanRequestReceivedEvent),
+    ChannelSelectionAlgorithm(ChannelSelectionAlgorithmEvent),
+    PathLossThreshold(PathLossThresholdEvent),
+    TransmitPowerReporting(TransmitPowerReportingEvent),
+    SubrateChange(SubrateChangeEvent),
+    ReadAllRemoteFeaturesComplete(OwnedReadAllRemoteFeaturesCompleteEvent),
+    Unknown {
+        subevent_code: u8,
+        parameters: Vec<u8, MAX_SUBEVENT_PARAMETERS_LEN>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedCommandCompleteEvent {
+    pub num_hci_command_packets: u8,
+    pub command_opcode: Opcode,
+    pub return_parameters: Vec<u8, MAX_RETURN_PARAMETERS_LEN>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedNumberOfCompletedPacketsEvent {
+    pub num_handles: u8,
+    pub handles: Vec<u8, MAX_COMPLETED_PACKETS_LEN>,
+}
+
+impl OwnedNumberOfCompletedPacketsEvent {
+    pub fn iter(&self) -> NumberOfCompletedPacketsEvent<'_> {
+        NumberOfCompletedPacketsEvent {
+            num_handles: self.num_handles,
+            reader: Reader::new(&self.handles),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedAdvertisingReports {
+    pub num_reports: u8,
+    pub reports: Vec<u8, MAX_REPORTS_LEN>,
+}
+
+impl OwnedAdvertisingReports {
+    pub fn iter(&self) -> AdvertisingReportIterator<'_> {
+        AdvertisingReportIterator {
+            num_reports: self.num_reports,
+            reader: Reader::new(&self.reports),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedDirectedAdvertisingReports {
+    pub num_reports: u8,
+    pub reports: Vec<u8, MAX_REPORTS_LEN>,
+}
+
+impl OwnedDirectedAdvertisingReports {
+    pub fn iter(&self) -> DirectedAdvertisingReportIterator<'_> {
+        DirectedAdvertisingReportIterator {
+            num_reports: self.num_reports,
+            reader: Reader::new(&self.reports),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedExtendedAdvertisingReports {
+    pub num_reports: u8,
+    pub reports: Vec<u8, MAX_REPORTS_LEN>,
+}
+
+impl OwnedExtendedAdvertisingReports {
+    pub fn iter(&self) -> ExtendedAdvertisingReportIterator<'_> {
+        ExtendedAdvertisingReportIterator {
+            num_reports: self.num_reports,
+            reader: Reader::new(&self.reports),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedReadAllRemoteFeaturesCompleteEvent {
+    pub status: u8,
+    pub connection_handle: u16,
+    pub max_remote_page: u8,
+    pub max_valid_page: u8,
+    pub features: Vec<u8, MAX_FEATURE_PAGES_LEN>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedAdvertisingReport {
+    pub event_type: u8,
+    pub address_type: u8,
+    pub address: BdAddr,
+    pub data: Vec<u8, MAX_ADVERTISING_DATA_LEN>,
+    pub rssi: i8,
+}
+
+impl OwnedAdvertisingReport {
+    pub fn data(&self) -> AdvertisingDataIterator<'_> {
+        AdvertisingDataIterator::new(Reader::new(&self.data))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedExtendedAdvertisingReport {
+    pub event_type: AdvEventProperties,
+    pub address_type: u8,
+    pub address: BdAddr,
+    pub primary_phy: u8,
+    pub secondary_phy: u8,
+    pub advertising_sid: u8,
+    pub tx_power: i8,
+    pub rssi: i8,
+    pub periodic_advertising_interval: u16,
+    pub direct_address_type: u8,
+    pub direct_address: BdAddr,
+    pub data: Vec<u8, MAX_EXTENDED_ADVERTISING_DATA_LEN>,
+}
+
+impl OwnedExtendedAdvertisingReport {
+    pub fn data(&self) -> AdvertisingDataIterator<'_> {
+        AdvertisingDataIterator::new(Reader::new(&self.data))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedPeriodicAdvertisingReportEvent {
+    pub sync_handle: u16,
+    pub tx_power: i8,
+    pub rssi: i8,
+    pub cte_type: u8,
+    pub data_status: u8,
+    pub data: Vec<u8, MAX_PERIODIC_ADVERTISING_DATA_LEN>,
+}
+
+impl OwnedPeriodicAdvertisingReportEvent {
+    pub fn data(&self) -> AdvertisingDataIterator<'_> {
+        AdvertisingDataIterator::new(Reader::new(&self.data))
+    }
+}
+
+// Copies the bytes the reader has not consumed yet, a partially consumed iterator keeps only the
+// entries that are still ahead of it.
+fn copy_remaining<const N: usize>(reader: &Reader) -> Option<Vec<u8, N>> {
+    Vec::from_slice(reader.peek_u8_slice(reader.remaining())?).ok()
+}
+
+impl HCIEvent<'_> {
+    /// Returns `None` if a slice is longer than the spec allows for its field.
+    pub fn to_owned(&self) -> Option<OwnedHCIEvent> {
+        Some(match self {
+            HCIEvent::DisconnectionComplete(event) => {
+                OwnedHCIEvent::DisconnectionComplete(event.clone())
+            }
+            HCIEvent::EncryptionChange(event) => OwnedHCIEvent::EncryptionChange(event.clone()),
+            HCIEvent::ReadRemoteVersionInformationComplete(event) => {
+                OwnedHCIEvent::ReadRemoteVersionInformationComplete(event.clone())
+            }
+            HCIEvent::CommandComplete(event) => OwnedHCIEvent::CommandComplete(event.to_owned()?),
+            HCIEvent::CommandStatus(event) => OwnedHCIEvent::CommandStatus(event.clone()),
+            HCIEvent::HardwareError(event) => OwnedHCIEvent::HardwareError(event.clone()),
+            HCIEvent::NumberOfCompletedPackets(event) => {
+                OwnedHCIEvent::NumberOfCompletedPackets(event.to_owned()?)
+            }
+            HCIEvent::DataBufferOverflow(event) => OwnedHCIEvent::DataBufferOverflow(event.clone()),
+            HCIEvent::LEMetaEvent(event) => OwnedHCIEvent::LEMetaEvent(event.to_owned()?),
+            HCIEvent::AuthenticatedPayloadTimeoutExpired(event) => {
+                OwnedHCIEvent::AuthenticatedPayloadTimeoutExpired(event.clone())
+            }
+            HCIEvent::Unknown { evcode, parameters } => OwnedHCIEvent::Unknown {
+                evcode: *evcode,
+                parameters: Vec::from_slice(parameters).ok()?,
+            },
+        })
+    }
+}
+
+impl LEMetaEvent<'_> {
+    pub fn to_owned(&self) -> Option<OwnedLEMetaEvent> {
+        Some(match self {
+            LEMetaEvent::ConnectionComplete(event) => {
+                OwnedLEMetaEvent::ConnectionComplete(event.clone())
+            }
+            LEMetaEvent::AdvertisingReport(reports) => {
+                OwnedLEMetaEvent::AdvertisingReport(OwnedAdvertisingReports {
+                    num_reports: reports.num_reports,
+                    reports: copy_remaining(&reports.reader)?,
+                })
+            }
+            LEMetaEvent::ConnectionUpdateComplete(event) => {
+                OwnedLEMetaEvent::ConnectionUpdateComplete(event.clone())
+            }
+            LEMetaEvent::ReadRemoteFeaturesComplete(event) => {
+                OwnedLEMetaEvent::ReadRemoteFeaturesComplete(event.clone())
+            }
+            LEMetaEvent::LongTermKeyRequest(event) => {
+                OwnedLEMetaEvent::LongTermKeyRequest(event.clone())
+            }
+            LEMetaEvent::RemoteConnectionParameterRequest(event) => {
+                OwnedLEMetaEvent::RemoteConnectionParameterRequest(event.clone())
+            }
+            LEMetaEvent::EnhancedConnectionComplete(event) => {
+                OwnedLEMetaEvent::EnhancedConnectionComplete(event.clone())
+            }
+            LEMetaEvent::DirectedAdvertisingReport(reports) => {
+                OwnedLEMetaEvent::DirectedAdvertisingReport(OwnedDirectedAdvertisingReports {
+                    num_reports: reports.num_reports,
+                    reports: copy_remaining(&reports.reader)?,
+                })
+            }
+            LEMetaEvent::PHYUpdateComplete(event) => {
+                OwnedLEMetaEvent::PHYUpdateComplete(event.clone())
+            }
+            LEMetaEvent::ExtendedAdvertisingReport(reports) => {
+                OwnedLEMetaEvent::ExtendedAdvertisingReport(OwnedExtendedAdvertisingReports {
+                    num_reports: reports.num_reports,
+                    reports: copy_remaining(&reports.reader)?,
+                })
+            }
+            LEMetaEvent::PeriodicAdvertisingSyncEstablished(event) => {
+                OwnedLEMetaEvent::PeriodicAdvertisingSyncEstablished(event.clone())
+            }
+            LEMetaEvent::PeriodicAdvertisingReport(event) => {
+                OwnedLEMetaEvent::PeriodicAdvertisingReport(OwnedPeriodicAdvertisingReportEvent {
+                    sync_handle: event.sync_handle,
+                    tx_power: event.tx_power,
+                    rssi: event.rssi,
+                    cte_type: event.cte_type,
+                    data_status: event.data_status,
+                    data: copy_remaining(event.data.reader())?,
+                })
+            }
+            LEMetaEvent::PeriodicAdvertisingSyncLost(event) => {
+                OwnedLEMetaEvent::PeriodicAdvertisingSyncLost(event.clone())
+            }
+            LEMetaEvent::ScanTimeout => OwnedLEMetaEvent::ScanTimeout,
+            LEMetaEvent::AdvertisingSetTerminated(event) => {
+                OwnedLEMetaEvent::AdvertisingSetTerminated(event.clone())
+            }
+            LEMetaEvent::ScanRequestReceived(event) => {
+                OwnedLEMetaEvent::ScanRequestReceived(event.clone())
+            }
+            LEMetaEvent::ChannelSelectionAlgorithm(event) => {
+                OwnedLEMetaEvent::ChannelSelectionAlgorithm(event.clone())
+            }
+            LEMetaEvent::PathLossThreshold(event) => {
+                OwnedLEMetaEvent::PathLossThreshold(event.clone())
+            }
+            LEMetaEvent::TransmitPowerReporting(event) => {
+                OwnedLEMetaEvent::TransmitPowerReporting(event.clone())
+            }
+            LEMetaEvent::SubrateChange(event) => OwnedLEMetaEvent::SubrateChange(event.clone()),
+            LEMetaEvent::ReadAllRemoteFeaturesComplete(event) => {
+                OwnedLEMetaEvent::ReadAllRemoteFeaturesComplete(
+                    OwnedReadAllRemoteFeaturesCompleteEvent {
+                        status: event.status,
+                        connection_handle: event.connection_handle,
+                        max_remote_page: event.max_remote_page,
+                        max_valid_page: event.max_valid_page,
+                        features: Vec::from_slice(event.features).ok()?,
+                    },
+                )
+            }
+            LEMetaEvent::Unknown {
+                subevent_code,
+                parameters,
+            } => OwnedLEMetaEvent::Unknown {
+                subevent_code: *subevent_code,
+                parameters: Vec::from_slice(parameters).ok()?,
+            },
+        })
+    }
+}
+
+impl CommandCompleteEvent<'_> {
+    pub fn to_owned(&self) -> Option<OwnedCommandCompleteEvent> {
+        Some(OwnedCommandCompleteEvent {
+            num_hci_command_packets: self.num_hci_command_packets,
+            command_opcode: self.command_opcode,
+            return_parameters: Vec::from_slice(self.return_parameters).ok()?,
+        })
+    }
+}
+
+impl NumberOfCompletedPacketsEvent<'_> {
+    pub fn to_owned(&self) -> Option<OwnedNumberOfCompletedPacketsEvent> {
+        Some(OwnedNumberOfCompletedPacketsEvent {
+            num_handles: self.num_handles,
+            handles: copy_remaining(&self.reader)?,
+        })
+    }
+}
+
+impl AdvertisingReport<'_> {
+    pub fn to_owned(&self) -> Option<OwnedAdvertisingReport> {
+        Some(OwnedAdvertisingReport {
+            event_type: self.event_type,
+            address_type: self.address_type,
+            address: self.address,
+            data: copy_remaining(self.data.reader())?,
+            rssi: self.rssi,
+        })
+    }
+}
+
+impl ExtendedAdvertisingReport<'_> {
+    pub fn to_owned(&self) -> Option<OwnedExtendedAdvertisingReport> {
+        Some(OwnedExtendedAdvertisingReport {
+            event_type: self.event_type,
+            address_type: self.address_type,
+            address: self.address,
+            primary_phy: self.primary_phy,
+            secondary_phy: self.secondary_phy,
+            advertising_sid: self.advertising_sid,
+            tx_power: self.tx_power,
+            rssi: self.rssi,
+            periodic_advertising_interval: self.periodic_advertising_interval,
+            direct_address_type: self.direct_address_type,
+            direct_address: self.direct_address,
+            data: copy_remaining(self.data.reader())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hci::{
+        AdvertisingData, HCIPacket,
+        fixtures::{LE_ADVERTISING_REPORT_ADV_IND, NUMBER_OF_COMPLETED_PACKETS},
+    };
+
+    #[test]
+    fn test_advertising_report_to_owned() {
+        let Some(HCIPacket::Event(packet)) = HCIPacket::from_buf(LE_ADVERTISING_REPORT_ADV_IND)
+        else {
+            panic!("Unexpected packet type");
+        };
+        let Ok(HCIEvent::LEMetaEvent(LEMetaEvent::AdvertisingReport(mut reports))) =
+            HCIEvent::from_packet(&packet)
+        else {
+            panic!("Unexpected event type");
+        };
+
+        let report = reports.next().unwrap().to_owned().unwrap();
+
+        assert_eq!(report.event_type, 0x00);
+        assert_eq!(report.address_type, 0x01);
+        assert_eq!(
+            report.address,
+            BdAddr::from_le_bytes([0x78, 0x56, 0x34, 0x12, 0x5A, 0xD6])
+        );
+        assert_eq!(report.rssi, -67);
+
+        let mut data = report.data();
+        assert_eq!(data.next(), Some(AdvertisingData::Flags(0x06)));
+        assert_eq!(
+            data.next(),
+            Some(AdvertisingData::CompleteLocalName("ble!"))
+        );
+        assert_eq!(data.next(), None);
+    }
+
+    #[test]
+    fn test_event_outlives_buffer() {
+        let owned = {
+            let buf: [u8; 24] = LE_ADVERTISING_REPORT_ADV_IND.try_into().unwrap();
+            let Some(HCIPacket::Event(packet)) = HCIPacket::from_buf(&buf) else {
+                panic!("Unexpected packet type");
+            };
+            HCIEvent::from_packet(&packet).unwrap().to_owned().unwrap()
+        };
+
+        let OwnedHCIEvent::LEMetaEvent(OwnedLEMetaEvent::AdvertisingReport(reports)) = owned else {
+            panic!("Unexpected event type");
+        };
+        assert_eq!(reports.num_reports, 1);
+
+        let mut reports = reports.iter();
+        assert_eq!(reports.next().unwrap().rssi, -67);
+        assert!(reports.next().is_none());
+    }
+
+    #[test]
+    fn test_number_of_completed_packets_to_owned() {
+        let Some(HCIPacket::Event(packet)) = HCIPacket::from_buf(NUMBER_OF_COMPLETED_PACKETS)
+        else {
+            panic!("Unexpected packet type");
+        };
+        let Ok(HCIEvent::NumberOfCompletedPackets(event)) = HCIEvent::from_packet(&packet) else {
+            panic!("Unexpected event type");
+        };
+
+        let owned = event.to_owned().unwrap();
+
+        assert!(owned.iter().eq(event));
+    }
+
+    #[test]
+    fn test_advertising_data_too_long() {
+        let buf = [0xAA; MAX_ADVERTISING_DATA_LEN + 1];
+        let report = AdvertisingReport {
+            event_type: 0x00,
+            address_type: 0x00,
+            address: BdAddr::default(),
+            data: AdvertisingDataIterator::new(Reader::new(&buf)),
+            rssi: 0,
+        };
+
+        assert_eq!(report.to_owned(), None);
+    }
+}
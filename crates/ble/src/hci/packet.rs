@@ -1,12 +1,18 @@
 use core::{any::type_name, fmt::Debug};
-use utils::reader::Reader;
+use utils::{
+    reader::Reader,
+    writer::{WriteError, Writer},
+};
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part A, Section 2 | page 1726
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HCIPacket<'p> {
     Command(HCICommandPacket<'p>),
     ACLData(HCIACLDataPacket<'p>),
+    Synchronous(HCISynchronousDataPacket<'p>),
     Event(HCIEventPacket<'p>),
+    ISOData(HCIISODataPacket<'p>),
     Unkown(&'p [u8]),
 }
 
@@ -27,39 +33,82 @@ impl<'p> HCIPacket<'p> {
                 let len = reader.read_u8()? as usize;
                 let data = reader.read_u8_slice(len)?;
 
-                Self::Command(HCICommandPacket::new(opcode, len, data))
+                Self::Command(HCICommandPacket::try_new(opcode, len, data)?)
             }
             Self::ACL_DATA_PACKET_TYPE => {
                 let header = reader.read_u16()?;
-                let handle = (header & 0b1111_1111_1111_0000) >> 4;
-                let flags = (header & 0b0000_0000_0000_1111) as u8;
-                let packet_boundary_flag = (flags & 0b0000_1100) >> 2;
-                let broadcast_flag = flags & 0b0000_0011;
+                let handle = header & 0b0000_1111_1111_1111;
+                let packet_boundary_flag = ((header >> 12) & 0b11) as u8;
+                let broadcast_flag = ((header >> 14) & 0b11) as u8;
                 let len = reader.read_u16()? as usize;
                 let data = reader.read_u8_slice(len)?;
 
-                Self::ACLData(HCIACLDataPacket::new(
+                Self::ACLData(HCIACLDataPacket::try_new(
                     handle,
                     packet_boundary_flag,
                     broadcast_flag,
                     len,
                     data,
-                ))
+                )?)
             }
             Self::SYNCHRONOUS_DATA_PACKET_TYPE => {
-                log::warn!("Synchonous data packet type not implemented yet");
-                Self::Unkown(buf)
+                let header = reader.read_u16()?;
+                let handle = header & 0b0000_1111_1111_1111;
+                let packet_status_flag = ((header >> 12) & 0b11) as u8;
+                let len = reader.read_u8()?;
+                let data = reader.read_u8_slice(len as usize)?;
+
+                Self::Synchronous(HCISynchronousDataPacket::new(
+                    handle,
+                    packet_status_flag,
+                    len,
+                    data,
+                ))
             }
             Self::EVENT_PACKET_TYPE => {
                 let evcode = reader.read_u8()?;
                 let len = reader.read_u8()? as usize;
                 let data = reader.read_u8_slice(len)?;
 
-                Self::Event(HCIEventPacket::new(evcode, len, data))
+                Self::Event(HCIEventPacket::try_new(evcode, len, data)?)
             }
             Self::ISO_DATA_PACKET_TYPE => {
-                log::warn!("ISO data packet type not implemented yet");
-                Self::Unkown(buf)
+                let header = reader.read_u16()?;
+                let handle = header & 0b0000_1111_1111_1111;
+                let packet_boundary_flag = ((header >> 12) & 0b11) as u8;
+                let timestamp_flag = ((header >> 14) & 0b1) as u8;
+                let len = (reader.read_u16()? & 0b0011_1111_1111_1111) as usize;
+                let mut reader = reader.sub_reader(len)?;
+
+                let timestamp = match timestamp_flag {
+                    1 => Some(reader.read_u32()?),
+                    _ => None,
+                };
+                let (packet_sequence_number, iso_sdu_length, packet_status_flag) =
+                    match HCIISODataPacket::has_sdu_header(packet_boundary_flag) {
+                        true => {
+                            let packet_sequence_number = reader.read_u16()?;
+                            let sdu_header = reader.read_u16()?;
+                            (
+                                Some(packet_sequence_number),
+                                Some(sdu_header & 0b0000_1111_1111_1111),
+                                Some((sdu_header >> 14) as u8),
+                            )
+                        }
+                        false => (None, None, None),
+                    };
+
+                Self::ISOData(HCIISODataPacket {
+                    handle,
+                    packet_boundary_flag,
+                    timestamp_flag,
+                    len,
+                    timestamp,
+                    packet_sequence_number,
+                    iso_sdu_length,
+                    packet_status_flag,
+                    data: reader.read_u8_slice(reader.remaining())?,
+                })
             }
             _ => {
                 log::warn!("Unknown HCI packet type: {}", packet_type);
@@ -67,10 +116,118 @@ impl<'p> HCIPacket<'p> {
             }
         })
     }
+
+    /// Writes the packet including its H4 packet type byte and returns the number of bytes written.
+    ///
+    /// [`HCIPacket::Unkown`] holds the complete packet and is written as is.
+    pub fn write(&self, writer: &mut Writer) -> Result<usize, WriteError> {
+        let start = writer.pos;
+
+        match self {
+            Self::Command(packet) => {
+                writer.write_u8(Self::COMMAND_PACKET_TYPE)?;
+                writer.write_u16(packet.opcode)?;
+                writer.write_u8(
+                    u8::try_from(packet.parameters.len()).map_err(|_| WriteError::InvalidFormat)?,
+                )?;
+                writer.write_u8_slice(packet.parameters)?;
+            }
+            Self::ACLData(packet) => {
+                if packet.handle > 0x0EFF
+                    || packet.packet_boundary_flag > 0b11
+                    || packet.broadcast_flag > 0b11
+                {
+                    return Err(WriteError::InvalidFormat);
+                }
+
+                writer.write_u8(Self::ACL_DATA_PACKET_TYPE)?;
+                writer.write_u16(
+                    packet.handle
+                        | (packet.packet_boundary_flag as u16) << 12
+                        | (packet.broadcast_flag as u16) << 14,
+                )?;
+                writer.write_u16(
+                    u16::try_from(packet.data.len()).map_err(|_| WriteError::InvalidFormat)?,
+                )?;
+                writer.write_u8_slice(packet.data)?;
+            }
+            Self::Synchronous(packet) => {
+                if packet.handle > 0x0EFF || packet.packet_status_flag > 0b11 {
+                    return Err(WriteError::InvalidFormat);
+                }
+
+                writer.write_u8(Self::SYNCHRONOUS_DATA_PACKET_TYPE)?;
+                writer.write_u16(packet.handle | (packet.packet_status_flag as u16) << 12)?;
+                writer.write_u8(
+                    u8::try_from(packet.data.len()).map_err(|_| WriteError::InvalidFormat)?,
+                )?;
+                writer.write_u8_slice(packet.data)?;
+            }
+            Self::Event(packet) => {
+                writer.write_u8(Self::EVENT_PACKET_TYPE)?;
+                writer.write_u8(packet.evcode)?;
+                writer.write_u8(
+                    u8::try_from(packet.parameters.len()).map_err(|_| WriteError::InvalidFormat)?,
+                )?;
+                writer.write_u8_slice(packet.parameters)?;
+            }
+            Self::ISOData(packet) => {
+                let sdu_header = match (
+                    packet.packet_sequence_number,
+                    packet.iso_sdu_length,
+                    packet.packet_status_flag,
+                ) {
+                    (Some(sequence_number), Some(sdu_length), Some(status))
+                        if sdu_length <= 0x0FFF && status <= 0b11 =>
+                    {
+                        Some((sequence_number, sdu_length | (status as u16) << 14))
+                    }
+                    (None, None, None) => None,
+                    _ => return Err(WriteError::InvalidFormat),
+                };
+
+                if packet.handle > 0x0EFF
+                    || packet.packet_boundary_flag > 0b11
+                    || packet.timestamp_flag != packet.timestamp.is_some() as u8
+                    || sdu_header.is_some()
+                        != HCIISODataPacket::has_sdu_header(packet.packet_boundary_flag)
+                {
+                    return Err(WriteError::InvalidFormat);
+                }
+
+                let len = packet.timestamp.map_or(0, |_| size_of::<u32>())
+                    + sdu_header.map_or(0, |_| 2 * size_of::<u16>())
+                    + packet.data.len();
+                if len > 0b0011_1111_1111_1111 {
+                    return Err(WriteError::InvalidFormat);
+                }
+
+                writer.write_u8(Self::ISO_DATA_PACKET_TYPE)?;
+                writer.write_u16(
+                    packet.handle
+                        | (packet.packet_boundary_flag as u16) << 12
+                        | (packet.timestamp_flag as u16) << 14,
+                )?;
+                writer.write_u16(len as u16)?;
+                if let Some(timestamp) = packet.timestamp {
+                    writer.write_u32(timestamp)?;
+                }
+                if let Some((sequence_number, sdu_header)) = sdu_header {
+                    writer.write_u16(sequence_number)?;
+                    writer.write_u16(sdu_header)?;
+                }
+                writer.write_u8_slice(packet.data)?;
+            }
+            Self::Unkown(buf) => writer.write_u8_slice(buf)?,
+        }
+
+        Ok(writer.pos - start)
+    }
 }
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.4 | page 1877
 // The Host shall be able to accept HCI Event packets with up to 255 octets of data excluding the HCI Event packet header
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct HCIEventPacket<'p> {
     pub evcode: u8,
     pub len: usize,
@@ -94,6 +251,16 @@ impl<'p> HCIEventPacket<'p> {
             parameters: &buf[..len],
         }
     }
+
+    /// Like [`HCIEventPacket::new`], but returns `None` instead of panicking if `len` is larger
+    /// than `buf`.
+    pub fn try_new(evcode: u8, len: usize, buf: &'p [u8]) -> Option<Self> {
+        Some(Self {
+            evcode,
+            len,
+            parameters: buf.get(..len)?,
+        })
+    }
 }
 
 impl Debug for HCIEventPacket<'_> {
@@ -101,13 +268,14 @@ impl Debug for HCIEventPacket<'_> {
         f.debug_struct(type_name::<Self>())
             .field("evcode", &self.evcode)
             .field("len", &self.len)
-            .field("parameters", &&self.parameters[..self.len])
+            .field("parameters", &self.parameters)
             .finish()
     }
 }
 
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.1 | page 1872
 // Controllers shall be able to accept HCI Command packets with up to 255 bytes of data excluding the HCI Command packet header.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct HCICommandPacket<'p> {
     pub opcode: u16,
     pub len: usize,
@@ -115,11 +283,9 @@ pub struct HCICommandPacket<'p> {
 }
 
 impl<'p> HCICommandPacket<'p> {
-    #[allow(unused)]
-    const HEADER_SIZE: usize = 3;
+    pub(crate) const HEADER_SIZE: usize = 3;
 
-    #[allow(unused)]
-    const MAX_PARAMETERS_SIZE: usize = 255;
+    pub(crate) const MAX_PARAMETERS_SIZE: usize = 255;
 
     #[allow(unused)]
     const MAX_PACKET_SIZE: usize = Self::HEADER_SIZE + Self::MAX_PARAMETERS_SIZE;
@@ -131,6 +297,16 @@ impl<'p> HCICommandPacket<'p> {
             parameters: &buf[..len],
         }
     }
+
+    /// Like [`HCICommandPacket::new`], but returns `None` instead of panicking if `len` is larger
+    /// than `buf`.
+    pub fn try_new(opcode: u16, len: usize, buf: &'p [u8]) -> Option<Self> {
+        Some(Self {
+            opcode,
+            len,
+            parameters: buf.get(..len)?,
+        })
+    }
 }
 
 impl Debug for HCICommandPacket<'_> {
@@ -138,7 +314,7 @@ impl Debug for HCICommandPacket<'_> {
         f.debug_struct(type_name::<Self>())
             .field("opcode", &self.opcode)
             .field("len", &self.len)
-            .field("parameters", &&self.parameters[..self.len])
+            .field("parameters", &self.parameters)
             .finish()
     }
 }
@@ -146,6 +322,7 @@ impl Debug for HCICommandPacket<'_> {
 // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.2 | page 1874
 // Hosts and Controllers shall be able to accept HCI ACL Data packets with up to 27 bytes of data excluding the HCI ACL Data packet header [...]
 // The HCI ACL Data packet header is the first 4 octets of the packet.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct HCIACLDataPacket<'p> {
     pub handle: u16,              // 12 bits
     pub packet_boundary_flag: u8, // 2 bits
@@ -158,6 +335,11 @@ impl<'p> HCIACLDataPacket<'p> {
     #[allow(unused)]
     const HEADER_SIZE: usize = 4;
 
+    pub const PB_FIRST_NON_FLUSHABLE: u8 = 0b00;
+    pub const PB_CONTINUATION_FRAGMENT: u8 = 0b01;
+    pub const PB_FIRST_FLUSHABLE: u8 = 0b10;
+    pub const PB_COMPLETE_FLUSHABLE: u8 = 0b11;
+
     #[allow(unused)]
     const MAX_DATA_LENGTH: usize = 27;
 
@@ -179,6 +361,24 @@ impl<'p> HCIACLDataPacket<'p> {
             data: &buf[..len],
         }
     }
+
+    /// Like [`HCIACLDataPacket::new`], but returns `None` instead of panicking if `len` is larger
+    /// than `buf`.
+    pub fn try_new(
+        handle: u16,
+        packet_boundary_flag: u8,
+        broadcast_flag: u8,
+        len: usize,
+        buf: &'p [u8],
+    ) -> Option<Self> {
+        Some(Self {
+            handle,
+            packet_boundary_flag,
+            broadcast_flag,
+            len,
+            data: buf.get(..len)?,
+        })
+    }
 }
 
 impl Debug for HCIACLDataPacket<'_> {
@@ -188,7 +388,324 @@ impl Debug for HCIACLDataPacket<'_> {
             .field("packet_boundary_flag", &self.packet_boundary_flag)
             .field("broadcast_flag", &self.broadcast_flag)
             .field("len", &self.len)
-            .field("data", &&self.data[..self.len])
+            .field("data", &self.data)
             .finish()
     }
 }
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.3
+// The HCI Synchronous Data packet header is the first 3 octets of the packet.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct HCISynchronousDataPacket<'p> {
+    pub handle: u16,            // 12 bits
+    pub packet_status_flag: u8, // 2 bits
+    pub len: u8,
+    pub data: &'p [u8],
+}
+
+impl<'p> HCISynchronousDataPacket<'p> {
+    #[allow(unused)]
+    const HEADER_SIZE: usize = 3;
+
+    pub fn new(handle: u16, packet_status_flag: u8, len: u8, buf: &'p [u8]) -> Self {
+        Self {
+            handle,
+            packet_status_flag,
+            len,
+            data: &buf[..len as usize],
+        }
+    }
+}
+
+impl Debug for HCISynchronousDataPacket<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct(type_name::<Self>())
+            .field("handle", &self.handle)
+            .field("packet_status_flag", &self.packet_status_flag)
+            .field("len", &self.len)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+// Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4.5
+// The timestamp is only present if timestamp_flag is set, the packet sequence number, ISO SDU length
+// and packet status flag only in the first fragment or a complete SDU.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct HCIISODataPacket<'p> {
+    pub handle: u16,              // 12 bits
+    pub packet_boundary_flag: u8, // 2 bits
+    pub timestamp_flag: u8,       // 1 bit
+    pub len: usize,               // 14 bits, ISO_Data_Load length
+    pub timestamp: Option<u32>,   // microseconds
+    pub packet_sequence_number: Option<u16>,
+    pub iso_sdu_length: Option<u16>,    // 12 bits
+    pub packet_status_flag: Option<u8>, // 2 bits
+    pub data: &'p [u8],                 // ISO_SDU_Fragment
+}
+
+impl HCIISODataPacket<'_> {
+    #[allow(unused)]
+    const HEADER_SIZE: usize = 4;
+
+    pub const PB_FIRST_FRAGMENT: u8 = 0b00;
+    pub const PB_CONTINUATION_FRAGMENT: u8 = 0b01;
+    pub const PB_COMPLETE_SDU: u8 = 0b10;
+    pub const PB_LAST_FRAGMENT: u8 = 0b11;
+
+    const fn has_sdu_header(packet_boundary_flag: u8) -> bool {
+        matches!(
+            packet_boundary_flag,
+            Self::PB_FIRST_FRAGMENT | Self::PB_COMPLETE_SDU
+        )
+    }
+}
+
+impl Debug for HCIISODataPacket<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct(type_name::<Self>())
+            .field("handle", &self.handle)
+            .field("packet_boundary_flag", &self.packet_boundary_flag)
+            .field("timestamp_flag", &self.timestamp_flag)
+            .field("len", &self.len)
+            .field("timestamp", &self.timestamp)
+            .field("packet_sequence_number", &self.packet_sequence_number)
+            .field("iso_sdu_length", &self.iso_sdu_length)
+            .field("packet_status_flag", &self.packet_status_flag)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    fn round_trip(buf: &[u8]) {
+        let packet = HCIPacket::from_buf(buf).unwrap();
+        let mut out = [0; 64];
+        let mut writer = Writer::new(&mut out);
+
+        let len = packet.write(&mut writer).unwrap();
+
+        assert_eq!(&out[..len], buf);
+    }
+
+    #[test]
+    fn test_command_round_trip() {
+        round_trip(&[
+            0x01, // HCI command packet
+            0x0C, 0x20, // OCF 0x000C, OGF 0x08
+            0x02, // parameter length
+            0x01, 0x00, // parameters
+        ]);
+    }
+
+    #[test]
+    fn test_acl_data_round_trip() {
+        let buf = [
+            0x02, // HCI ACL data packet
+            0x40, 0x20, // handle 0x040, PB 0b10, BC 0b00
+            0x03, 0x00, // data length
+            0xAA, 0xBB, 0xCC, // data
+        ];
+
+        let Some(HCIPacket::ACLData(packet)) = HCIPacket::from_buf(&buf) else {
+            panic!("Unexpected packet type");
+        };
+        assert_eq!(packet.handle, 0x0040);
+        assert_eq!(packet.packet_boundary_flag, 0b10);
+        assert_eq!(packet.broadcast_flag, 0b00);
+        assert_eq!(packet.data, &[0xAA, 0xBB, 0xCC]);
+
+        round_trip(&buf);
+    }
+
+    #[test]
+    fn test_acl_data_truncated() {
+        let buf = [
+            0x02, // ACL data packet
+            0x40, 0x20, // handle 0x040, first flushable
+            0x05, 0x00, // data length larger than the payload
+            0xAA, 0xBB, 0xCC,
+        ];
+
+        assert!(HCIPacket::from_buf(&buf).is_none());
+        assert!(HCIACLDataPacket::try_new(0x0040, 0b10, 0b00, 5, &buf[5..]).is_none());
+        assert!(HCIACLDataPacket::try_new(0x0040, 0b10, 0b00, 3, &buf[5..]).is_some());
+    }
+
+    #[test]
+    fn test_synchronous_data() {
+        let buf = [
+            0x03, // HCI synchronous data packet
+            0x05, 0x10, // handle 0x005, packet status flag 0b01
+            0x03, // data length
+            0x11, 0x22, 0x33, // data
+        ];
+
+        let Some(HCIPacket::Synchronous(packet)) = HCIPacket::from_buf(&buf) else {
+            panic!("Unexpected packet type");
+        };
+        assert_eq!(packet.handle, 0x0005);
+        assert_eq!(packet.packet_status_flag, 0b01);
+        assert_eq!(packet.len, 3);
+        assert_eq!(packet.data, &[0x11, 0x22, 0x33]);
+
+        round_trip(&buf);
+    }
+
+    #[test]
+    fn test_synchronous_data_truncated() {
+        assert!(HCIPacket::from_buf(&[0x03, 0x05, 0x10, 0x03, 0x11]).is_none());
+    }
+
+    #[test]
+    fn test_iso_data_with_timestamp() {
+        let buf = [
+            0x05, // HCI ISO data packet
+            0x60, 0x60, // handle 0x060, PB complete SDU, timestamp present
+            0x0B, 0x00, // data load length
+            0x78, 0x56, 0x34, 0x12, // timestamp
+            0x01, 0x00, // packet sequence number
+            0x03, 0x00, // ISO SDU length 3, packet status valid
+            0xAA, 0xBB, 0xCC, // ISO SDU fragment
+        ];
+
+        let Some(HCIPacket::ISOData(packet)) = HCIPacket::from_buf(&buf) else {
+            panic!("Unexpected packet type");
+        };
+        assert_eq!(packet.handle, 0x0060);
+        assert_eq!(
+            packet.packet_boundary_flag,
+            HCIISODataPacket::PB_COMPLETE_SDU
+        );
+        assert_eq!(packet.timestamp_flag, 1);
+        assert_eq!(packet.len, 11);
+        assert_eq!(packet.timestamp, Some(0x12345678));
+        assert_eq!(packet.packet_sequence_number, Some(0x0001));
+        assert_eq!(packet.iso_sdu_length, Some(3));
+        assert_eq!(packet.packet_status_flag, Some(0b00));
+        assert_eq!(packet.data, &[0xAA, 0xBB, 0xCC]);
+
+        round_trip(&buf);
+    }
+
+    #[test]
+    fn test_iso_data_without_timestamp() {
+        let buf = [
+            0x05, // HCI ISO data packet
+            0x60, 0x00, // handle 0x060, PB first fragment, no timestamp
+            0x06, 0x00, // data load length
+            0x02, 0x00, // packet sequence number
+            0x08, 0x40, // ISO SDU length 8, packet status possibly invalid
+            0xAA, 0xBB, // ISO SDU fragment
+        ];
+
+        let Some(HCIPacket::ISOData(packet)) = HCIPacket::from_buf(&buf) else {
+            panic!("Unexpected packet type");
+        };
+        assert_eq!(
+            packet.packet_boundary_flag,
+            HCIISODataPacket::PB_FIRST_FRAGMENT
+        );
+        assert_eq!(packet.timestamp_flag, 0);
+        assert_eq!(packet.timestamp, None);
+        assert_eq!(packet.packet_sequence_number, Some(0x0002));
+        assert_eq!(packet.iso_sdu_length, Some(8));
+        assert_eq!(packet.packet_status_flag, Some(0b01));
+        assert_eq!(packet.data, &[0xAA, 0xBB]);
+
+        round_trip(&buf);
+    }
+
+    #[test]
+    fn test_iso_data_continuation_fragment() {
+        let buf = [
+            0x05, // HCI ISO data packet
+            0x60, 0x30, // handle 0x060, PB last fragment, no timestamp
+            0x03, 0x00, // data load length
+            0xCC, 0xDD, 0xEE, // ISO SDU fragment
+        ];
+
+        let Some(HCIPacket::ISOData(packet)) = HCIPacket::from_buf(&buf) else {
+            panic!("Unexpected packet type");
+        };
+        assert_eq!(
+            packet.packet_boundary_flag,
+            HCIISODataPacket::PB_LAST_FRAGMENT
+        );
+        assert_eq!(packet.packet_sequence_number, None);
+        assert_eq!(packet.iso_sdu_length, None);
+        assert_eq!(packet.data, &[0xCC, 0xDD, 0xEE]);
+
+        round_trip(&buf);
+    }
+
+    #[test]
+    fn test_event_round_trip() {
+        round_trip(&[
+            0x04, // HCI event packet
+            0x0E, // Command Complete
+            0x04, // parameter length
+            0x01, 0x03, 0x0C, 0x00, // parameters
+        ]);
+    }
+
+    #[test]
+    fn test_event_truncated() {
+        let buf = [
+            0x04, // HCI event packet
+            0x0E, // Command Complete
+            0x04, // parameter length larger than the parameters
+            0x01, 0x03, 0x0C,
+        ];
+
+        assert!(HCIPacket::from_buf(&buf).is_none());
+        assert!(HCIEventPacket::try_new(0x0E, 4, &buf[3..]).is_none());
+        assert!(HCIEventPacket::try_new(0x0E, 3, &buf[3..]).is_some());
+    }
+
+    #[test]
+    fn test_command_truncated() {
+        let buf = [
+            0x01, // HCI command packet
+            0x0C, 0x20, // LE Set Scan Enable
+            0x02, // parameter length larger than the parameters
+            0x01,
+        ];
+
+        assert!(HCIPacket::from_buf(&buf).is_none());
+        assert!(HCICommandPacket::try_new(0x200C, 2, &buf[4..]).is_none());
+        assert!(HCICommandPacket::try_new(0x200C, 1, &buf[4..]).is_some());
+    }
+
+    #[test]
+    fn test_debug_with_inconsistent_len() {
+        let packet = HCIEventPacket {
+            evcode: 0x0E,
+            len: 8,
+            parameters: &[0x01, 0x03, 0x0C, 0x00],
+        };
+
+        assert!(std::format!("{packet:?}").contains("parameters: [1, 3, 12, 0]"));
+    }
+
+    #[test]
+    fn test_unknown_round_trip() {
+        round_trip(&[0x06, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_write_buffer_overflow() {
+        let packet = HCIPacket::from_buf(&[0x04, 0x0E, 0x01, 0x01]).unwrap();
+        let mut out = [0; 3];
+
+        assert!(matches!(
+            packet.write(&mut Writer::new(&mut out)),
+            Err(WriteError::BufferOverflow)
+        ));
+    }
+}
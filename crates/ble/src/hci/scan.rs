@@ -0,0 +1,221 @@
+use alloc::vec::Vec;
+use utils::{
+    reader::Reader,
+    tlv::{LengthField, TlvIterator},
+};
+
+use super::{
+    HCIEvent, LEMetaEvent,
+    address::BdAddr,
+    event::{AdvertisingDataIterator, AdvertisingReport},
+};
+
+/// A device seen while scanning, with the advertising data of all its reports merged together.
+#[derive(Debug)]
+pub struct ScanResult {
+    pub address: BdAddr,
+    pub address_type: u8,
+    pub event_type: u8,
+    pub rssi: i8,
+    data: Vec<u8>,
+}
+
+impl ScanResult {
+    pub fn data(&self) -> AdvertisingDataIterator<'_> {
+        AdvertisingDataIterator::new(Reader::new(&self.data))
+    }
+
+    // Every AD structure in `data` replaces a previously seen structure of the same type.
+    fn merge(&mut self, data: &[u8]) {
+        for (ad_type, value) in ad_structures(data) {
+            let mut merged = Vec::with_capacity(self.data.len() + value.len() + 2);
+
+            for (existing_type, existing_value) in ad_structures(&self.data) {
+                if existing_type != ad_type {
+                    push_ad_structure(&mut merged, existing_type, existing_value);
+                }
+            }
+
+            push_ad_structure(&mut merged, ad_type, value);
+            self.data = merged;
+        }
+    }
+}
+
+// Frames AD structures the same way as `AdvertisingDataIterator`, padding and truncated
+// structures are dropped.
+fn ad_structures(data: &[u8]) -> TlvIterator<'_> {
+    TlvIterator::new(Reader::new(data), LengthField::U8, true)
+}
+
+fn push_ad_structure(data: &mut Vec<u8>, ad_type: u8, value: &[u8]) {
+    data.push((value.len() + size_of::<u8>()) as u8);
+    data.push(ad_type);
+    data.extend_from_slice(value);
+}
+
+/// Accumulates advertising reports into one [`ScanResult`] per device address.
+///
+/// Call [`ReportCollector::clear`] to start collecting a new window of reports.
+#[derive(Debug, Default)]
+pub struct ReportCollector {
+    devices: Vec<ScanResult>,
+}
+
+impl ReportCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, event: HCIEvent<'_>) {
+        if let HCIEvent::LEMetaEvent(LEMetaEvent::AdvertisingReport(reports)) = event {
+            for report in reports {
+                self.push_report(report);
+            }
+        }
+    }
+
+    pub fn devices(&self) -> impl Iterator<Item = &ScanResult> {
+        self.devices.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.devices.clear();
+    }
+
+    fn push_report(&mut self, report: AdvertisingReport<'_>) {
+        let address = report.address;
+        let reader = report.data.reader();
+        let data = reader.peek_u8_slice(reader.remaining()).unwrap_or_default();
+
+        let device = match self.devices.iter_mut().position(|d| d.address == address) {
+            Some(index) => &mut self.devices[index],
+            None => {
+                self.devices.push(ScanResult {
+                    address,
+                    address_type: report.address_type,
+                    event_type: report.event_type,
+                    rssi: report.rssi,
+                    data: Vec::new(),
+                });
+                self.devices.last_mut().unwrap()
+            }
+        };
+
+        device.address_type = report.address_type;
+        device.event_type = report.event_type;
+        device.rssi = report.rssi;
+        device.merge(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hci::{AdvertisingData, HCIEventCode, HCIEventPacket, SubeventCode};
+
+    fn advertising_report_packet<'p>(buf: &'p mut [u8], report: &[u8]) -> HCIEventPacket<'p> {
+        buf[0] = SubeventCode::AdvertisingReport.into();
+        buf[1] = 1;
+        buf[2..2 + report.len()].copy_from_slice(report);
+
+        HCIEventPacket::new(HCIEventCode::LEMetaEvent.into(), 2 + report.len(), buf)
+    }
+
+    #[test]
+    fn test_collect_reports_for_two_devices() {
+        let reports: [&[u8]; 4] = [
+            // ADV_IND from device A with flags and a shortened name, RSSI -60
+            &[
+                0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x06, 0x02, 0x01, 0x06, 0x02, 0x08,
+                b'a', 0xC4,
+            ],
+            // ADV_NONCONN_IND from device B with flags, RSSI -70
+            &[
+                0x03, 0x01, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x03, 0x02, 0x01, 0x04, 0xBA,
+            ],
+            // SCAN_RSP from device A with a complete name, RSSI -55
+            &[
+                0x04, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x04, 0x03, 0x09, b'a', b'b', 0xC9,
+            ],
+            // ADV_IND from device A with updated flags, RSSI -50
+            &[
+                0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x03, 0x02, 0x01, 0x02, 0xCE,
+            ],
+        ];
+
+        let mut collector = ReportCollector::new();
+
+        for report in reports {
+            let mut buf = [0; 32];
+            let packet = advertising_report_packet(&mut buf, report);
+            collector.push(HCIEvent::from_packet(&packet).unwrap());
+        }
+
+        let mut devices = collector.devices();
+
+        let a = devices.next().unwrap();
+        assert_eq!(
+            a.address,
+            BdAddr::from_le_bytes([0x01, 0x02, 0x03, 0x04, 0x05, 0x06])
+        );
+        assert_eq!(a.rssi, -50);
+        assert_eq!(a.event_type, 0x00);
+
+        let mut data = a.data();
+        assert!(matches!(
+            data.next(),
+            Some(AdvertisingData::ShortenedLocalName("a"))
+        ));
+        assert!(matches!(
+            data.next(),
+            Some(AdvertisingData::CompleteLocalName("ab"))
+        ));
+        assert!(matches!(data.next(), Some(AdvertisingData::Flags(0x02))));
+        assert!(data.next().is_none());
+
+        let b = devices.next().unwrap();
+        assert_eq!(
+            b.address,
+            BdAddr::from_le_bytes([0x11, 0x12, 0x13, 0x14, 0x15, 0x16])
+        );
+        assert_eq!(b.address_type, 0x01);
+        assert_eq!(b.rssi, -70);
+        assert!(matches!(
+            b.data().next(),
+            Some(AdvertisingData::Flags(0x04))
+        ));
+
+        assert!(devices.next().is_none());
+    }
+
+    #[test]
+    fn test_collect_reports_with_padding_and_truncated_data() {
+        let reports: [&[u8]; 2] = [
+            // ADV_IND with flags followed by zero padding
+            &[
+                0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x05, 0x02, 0x01, 0x06, 0x00, 0x00,
+                0xC4,
+            ],
+            // SCAN_RSP whose complete name is cut off
+            &[
+                0x04, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x04, 0x05, 0x09, b'a', b'b', 0xC9,
+            ],
+        ];
+
+        let mut collector = ReportCollector::new();
+
+        for report in reports {
+            let mut buf = [0; 32];
+            let packet = advertising_report_packet(&mut buf, report);
+            collector.push(HCIEvent::from_packet(&packet).unwrap());
+        }
+
+        let device = collector.devices().next().unwrap();
+        let mut data = device.data();
+
+        assert!(matches!(data.next(), Some(AdvertisingData::Flags(0x06))));
+        assert!(data.next().is_none());
+        assert_eq!(device.data, [0x02, 0x01, 0x06]);
+    }
+}
@@ -0,0 +1,79 @@
+use utils::reader::Reader;
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part A, Section 2.1
+pub const ATT_CID: u16 = 0x0004;
+pub const LE_SIGNALING_CID: u16 = 0x0005;
+pub const SECURITY_MANAGER_CID: u16 = 0x0006;
+
+// Bluetooth Core spec 6.0 | [Vol 3] Part A, Section 3.1
+// length is the size of the information payload, excluding the basic header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct L2capHeader {
+    pub length: u16,
+    pub channel_id: u16,
+}
+
+impl L2capHeader {
+    pub const SIZE: usize = 4;
+
+    /// Reads the basic header and its payload, `None` unless the remaining data is exactly
+    /// `length` bytes.
+    pub fn parse<'p>(reader: &mut Reader<'p>) -> Option<(Self, &'p [u8])> {
+        let header = Self {
+            length: reader.read_u16()?,
+            channel_id: reader.read_u16()?,
+        };
+
+        if reader.remaining() != header.length as usize {
+            return None;
+        }
+
+        Some((header, reader.read_u8_slice(reader.remaining())?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_att() {
+        // ATT Exchange MTU Request
+        let buf = [0x03, 0x00, 0x04, 0x00, 0x02, 0xF7, 0x00];
+        let mut reader = Reader::new(&buf);
+
+        let (header, payload) = L2capHeader::parse(&mut reader).unwrap();
+
+        assert_eq!(
+            header,
+            L2capHeader {
+                length: 3,
+                channel_id: ATT_CID
+            }
+        );
+        assert_eq!(payload, &[0x02, 0xF7, 0x00]);
+    }
+
+    #[test]
+    fn test_parse_signaling() {
+        // Connection Parameter Update Response, accepted
+        let buf = [0x06, 0x00, 0x05, 0x00, 0x13, 0x01, 0x02, 0x00, 0x00, 0x00];
+        let mut reader = Reader::new(&buf);
+
+        let (header, payload) = L2capHeader::parse(&mut reader).unwrap();
+
+        assert_eq!(header.length, 6);
+        assert_eq!(header.channel_id, LE_SIGNALING_CID);
+        assert_eq!(payload, &[0x13, 0x01, 0x02, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_parse_length_mismatch() {
+        let short = [0x03, 0x00, 0x04, 0x00, 0x02, 0xF7];
+        let long = [0x01, 0x00, 0x04, 0x00, 0x02, 0xF7];
+
+        assert_eq!(L2capHeader::parse(&mut Reader::new(&short)), None);
+        assert_eq!(L2capHeader::parse(&mut Reader::new(&long)), None);
+        assert_eq!(L2capHeader::parse(&mut Reader::new(&[0x00, 0x00])), None);
+    }
+}
@@ -1,3 +1,15 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod att;
+mod error;
+#[cfg(feature = "alloc")]
+pub mod gatt;
 pub mod hci;
+pub mod l2cap;
+#[cfg(feature = "transport")]
+pub mod transport;
+
+pub use error::Error;
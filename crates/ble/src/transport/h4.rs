@@ -0,0 +1,188 @@
+use crate::hci::HCIPacket;
+
+use super::TransportError;
+
+/// Frames a stream of H4 bytes, as delivered by a UART, into complete packets.
+///
+/// Partial packets are buffered until the length declared in their header has arrived. A
+/// complete packet stays available through [`H4Decoder::packet`] until the next
+/// [`H4Decoder::push`].
+pub struct H4Decoder<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> H4Decoder<N> {
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Buffers bytes of the current packet and returns how many of `bytes` were consumed.
+    ///
+    /// Consumption stops once a packet is complete, the remaining bytes belong to the next packet
+    /// and have to be pushed again after taking it. On error the buffered bytes are dropped.
+    pub fn push(&mut self, bytes: &[u8]) -> Result<usize, TransportError> {
+        if self.is_complete() {
+            self.len = 0;
+        }
+
+        let mut consumed = 0;
+        loop {
+            let target = match self.target_len() {
+                Ok(target) if target <= N => target,
+                Ok(_) => return Err(self.reset(TransportError::BufferOverflow)),
+                Err(error) => return Err(self.reset(error)),
+            };
+            let remaining = &bytes[consumed..];
+            if self.len == target || remaining.is_empty() {
+                break;
+            }
+
+            let take = (target - self.len).min(remaining.len());
+            self.buf[self.len..self.len + take].copy_from_slice(&remaining[..take]);
+            self.len += take;
+            consumed += take;
+        }
+
+        Ok(consumed)
+    }
+
+    /// Returns how many bytes are missing to make progress on the current packet, reading no
+    /// more than this from a stream never reads into the next packet.
+    pub fn needed(&self) -> usize {
+        match self.target_len() {
+            Ok(target) if target != self.len => target - self.len,
+            _ => 1,
+        }
+    }
+
+    /// Returns the packet once all of its bytes have been pushed.
+    pub fn packet(&self) -> Option<HCIPacket<'_>> {
+        match self.is_complete() {
+            true => HCIPacket::from_buf(&self.buf[..self.len]),
+            false => None,
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.target_len() == Ok(self.len)
+    }
+
+    // The number of bytes needed to make progress, the packet type byte, the rest of the header or
+    // the complete packet once the header is known.
+    fn target_len(&self) -> Result<usize, TransportError> {
+        let Some(&packet_type) = self.buf[..self.len].first() else {
+            return Ok(1);
+        };
+
+        // Bluetooth Core spec 6.0 | [Vol 4] Part E, Section 5.4
+        let (header_len, payload_len): (usize, fn(&[u8]) -> usize) = match packet_type {
+            HCIPacket::COMMAND_PACKET_TYPE => (3, |header| header[2] as usize),
+            HCIPacket::ACL_DATA_PACKET_TYPE => (4, |header| {
+                u16::from_le_bytes([header[2], header[3]]) as usize
+            }),
+            HCIPacket::SYNCHRONOUS_DATA_PACKET_TYPE => (3, |header| header[2] as usize),
+            HCIPacket::EVENT_PACKET_TYPE => (2, |header| header[1] as usize),
+            HCIPacket::ISO_DATA_PACKET_TYPE => (4, |header| {
+                (u16::from_le_bytes([header[2], header[3]]) & 0b0011_1111_1111_1111) as usize
+            }),
+            _ => return Err(TransportError::InvalidPacketType(packet_type)),
+        };
+
+        match self.buf[1..self.len].get(..header_len) {
+            Some(header) => Ok(1 + header_len + payload_len(header)),
+            None => Ok(1 + header_len),
+        }
+    }
+
+    fn reset(&mut self, error: TransportError) -> TransportError {
+        self.len = 0;
+        error
+    }
+}
+
+impl<const N: usize> Default for H4Decoder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hci::{HCIEvent, fixtures::RESET_COMMAND_COMPLETE};
+
+    #[test]
+    fn test_event_split_across_pushes() {
+        let mut decoder = H4Decoder::<64>::new();
+        let (packet_type, rest) = RESET_COMMAND_COMPLETE.split_at(1);
+        let (header, parameters) = rest.split_at(2);
+
+        assert_eq!(decoder.push(packet_type), Ok(1));
+        assert!(decoder.packet().is_none());
+        assert_eq!(decoder.push(header), Ok(2));
+        assert!(decoder.packet().is_none());
+        assert_eq!(decoder.push(parameters), Ok(parameters.len()));
+
+        let Some(HCIPacket::Event(packet)) = decoder.packet() else {
+            panic!("Unexpected packet type");
+        };
+        assert!(matches!(
+            HCIEvent::from_packet(&packet),
+            Ok(HCIEvent::CommandComplete(_))
+        ));
+    }
+
+    #[test]
+    fn test_push_stops_after_complete_packet() {
+        let mut decoder = H4Decoder::<64>::new();
+        let stream = [
+            0x02, 0x01, 0x20, 0x02, 0x00, 0xAA, 0xBB, // ACL data packet
+            0x04, 0x13, 0x05, // start of an event packet
+        ];
+
+        assert_eq!(decoder.push(&stream), Ok(7));
+        let Some(HCIPacket::ACLData(packet)) = decoder.packet() else {
+            panic!("Unexpected packet type");
+        };
+        assert_eq!(packet.data, &[0xAA, 0xBB]);
+
+        assert_eq!(decoder.push(&stream[7..]), Ok(3));
+        assert!(decoder.packet().is_none());
+    }
+
+    #[test]
+    fn test_iso_data_length() {
+        let mut decoder = H4Decoder::<64>::new();
+        // The upper two bits of the data load length are reserved
+        let stream = [0x05, 0x60, 0x30, 0x01, 0xC0, 0xAA, 0xBB];
+
+        assert_eq!(decoder.push(&stream), Ok(6));
+        assert!(matches!(decoder.packet(), Some(HCIPacket::ISOData(_))));
+    }
+
+    #[test]
+    fn test_invalid_packet_type() {
+        let mut decoder = H4Decoder::<64>::new();
+
+        assert_eq!(
+            decoder.push(&[0x07, 0x00]),
+            Err(TransportError::InvalidPacketType(0x07))
+        );
+        assert_eq!(decoder.push(&[0x04, 0x13, 0x00]), Ok(3));
+        assert!(matches!(decoder.packet(), Some(HCIPacket::Event(_))));
+    }
+
+    #[test]
+    fn test_packet_exceeds_buffer() {
+        let mut decoder = H4Decoder::<8>::new();
+
+        assert_eq!(
+            decoder.push(&[0x04, 0x0E, 0x10]),
+            Err(TransportError::BufferOverflow)
+        );
+    }
+}
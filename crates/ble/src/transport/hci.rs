@@ -0,0 +1,153 @@
+use crate::{
+    Error,
+    hci::{HCICommand, HCIEvent, HCIPacket},
+};
+use utils::reader::ReadError;
+
+use super::Transport;
+
+/// Ties a [`Transport`] to the packet and event parsers.
+///
+/// Received packets are stored in an internal buffer, so the returned packets and events borrow
+/// from the [`Hci`] until the next call.
+pub struct Hci<T: Transport> {
+    transport: T,
+    buf: [u8; EVENT_BUFFER_SIZE],
+}
+
+// H4 packet type + event packet header + 255 bytes of event parameters
+const EVENT_BUFFER_SIZE: usize = 1 + 2 + 255;
+
+// H4 packet type + command packet header + 255 bytes of command parameters
+const COMMAND_BUFFER_SIZE: usize = 1 + 3 + 255;
+
+impl<T: Transport> Hci<T> {
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            buf: [0; EVENT_BUFFER_SIZE],
+        }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn into_transport(self) -> T {
+        self.transport
+    }
+
+    pub fn send(&mut self, command: &HCICommand) -> Result<(), Error> {
+        let mut buf = [0; COMMAND_BUFFER_SIZE];
+        let len = command.write_into(&mut buf)?;
+
+        Ok(self.transport.write(&buf[..len])?)
+    }
+
+    /// Reads the next packet of any type.
+    pub fn next_packet(&mut self) -> Result<HCIPacket<'_>, Error> {
+        let len = self.transport.read(&mut self.buf)?;
+
+        HCIPacket::from_buf(&self.buf[..len]).ok_or(Error::Read(ReadError::BufferUnderflow))
+    }
+
+    /// Reads packets until an event packet arrives and parses it, other packets are skipped.
+    ///
+    /// Events without a parser, such as vendor specific events, are returned as
+    /// [`Error::Parse`], the next call continues with the following packet.
+    pub fn next_event(&mut self) -> Result<HCIEvent<'_>, Error> {
+        let len = loop {
+            let len = self.transport.read(&mut self.buf)?;
+
+            if self.buf[..len].first() == Some(&HCIPacket::EVENT_PACKET_TYPE) {
+                break len;
+            }
+
+            log::debug!("Skipping non-event packet");
+        };
+
+        let Some(HCIPacket::Event(packet)) = HCIPacket::from_buf(&self.buf[..len]) else {
+            return Err(Error::Read(ReadError::BufferUnderflow));
+        };
+
+        Ok(HCIEvent::from_packet(&packet)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hci::{
+            HciParseError,
+            fixtures::{
+                LE_ADVERTISING_REPORT_ADV_IND, RESET_COMMAND_COMPLETE, VENDOR_SPECIFIC_EVENT,
+            },
+        },
+        transport::{TransportError, mock::MockTransport},
+    };
+
+    const ACL_DATA: &[u8] = &[
+        0x02, // ACL data packet
+        0x01, 0x20, // handle and flags
+        0x02, 0x00, // data length
+        0xAA, 0xBB, // data
+    ];
+
+    #[test]
+    fn test_reset_command_complete() {
+        let incoming = [RESET_COMMAND_COMPLETE];
+        let mut hci = Hci::new(MockTransport::new(&incoming));
+
+        hci.send(&HCICommand::Reset).unwrap();
+        assert_eq!(hci.transport().written(), &[0x01, 0x03, 0x0C, 0x00]);
+
+        let Ok(HCIEvent::CommandComplete(event)) = hci.next_event() else {
+            panic!("Unexpected event type");
+        };
+
+        assert_eq!(event.num_hci_command_packets, 1);
+        assert_eq!(event.command_opcode, HCICommand::Reset.opcode());
+        assert_eq!(event.return_parameters, &[0x00]);
+    }
+
+    #[test]
+    fn test_next_event_skips_non_event_packets() {
+        let incoming = [ACL_DATA, RESET_COMMAND_COMPLETE];
+        let mut hci = Hci::new(MockTransport::new(&incoming));
+
+        assert!(matches!(hci.next_event(), Ok(HCIEvent::CommandComplete(_))));
+        assert!(matches!(
+            hci.next_event(),
+            Err(Error::Transport(TransportError::Io))
+        ));
+    }
+
+    #[test]
+    fn test_next_event_unknown_event_code() {
+        let incoming = [VENDOR_SPECIFIC_EVENT, RESET_COMMAND_COMPLETE];
+        let mut hci = Hci::new(MockTransport::new(&incoming));
+
+        assert!(matches!(
+            hci.next_event(),
+            Err(Error::Parse(HciParseError::NotImplemented {
+                evcode: 0xFF,
+                sub_evcode: None,
+            }))
+        ));
+        assert!(matches!(hci.next_event(), Ok(HCIEvent::CommandComplete(_))));
+    }
+
+    #[test]
+    fn test_next_packet() {
+        let incoming = [ACL_DATA, LE_ADVERTISING_REPORT_ADV_IND];
+        let mut hci = Hci::new(MockTransport::new(&incoming));
+
+        let Ok(HCIPacket::ACLData(packet)) = hci.next_packet() else {
+            panic!("Unexpected packet type");
+        };
+        assert_eq!(packet.data, &[0xAA, 0xBB]);
+
+        assert!(matches!(hci.next_packet(), Ok(HCIPacket::Event(_))));
+    }
+}
@@ -0,0 +1,54 @@
+use core::fmt::Display;
+
+use crate::hci::HCIPacket;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportError {
+    /// The underlying transport failed to read or write.
+    Io,
+    /// The packet does not fit into the provided buffer.
+    BufferOverflow,
+    /// The H4 packet type byte is not one of the HCI packet types.
+    InvalidPacketType(u8),
+}
+
+impl Display for TransportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io => write!(f, "Io"),
+            Self::BufferOverflow => write!(f, "BufferOverflow"),
+            Self::InvalidPacketType(packet_type) => write!(f, "InvalidPacketType({packet_type})"),
+        }
+    }
+}
+
+impl core::error::Error for TransportError {}
+
+/// A byte transport carrying complete H4 framed packets (packet type byte followed by the packet).
+pub trait Transport {
+    /// Reads one complete packet into `buf` and returns its length.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, TransportError>;
+
+    /// Writes one complete packet.
+    fn write(&mut self, packet: &[u8]) -> Result<(), TransportError>;
+}
+
+/// The async counterpart of [`Transport`], exchanging parsed packets.
+///
+/// The futures are not required to be `Send`, as on single threaded executors like embassy.
+#[allow(async_fn_in_trait)]
+pub trait AsyncTransport {
+    /// Reads the next packet into `buf`.
+    async fn read<'b>(&mut self, buf: &'b mut [u8]) -> Result<HCIPacket<'b>, TransportError>;
+
+    async fn write(&mut self, packet: &HCIPacket<'_>) -> Result<(), TransportError>;
+}
+
+/// An async byte stream without any framing, such as a UART.
+#[allow(async_fn_in_trait)]
+pub trait AsyncSerial {
+    /// Reads at most `buf.len()` bytes and returns how many were read.
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, TransportError>;
+
+    async fn write(&mut self, bytes: &[u8]) -> Result<(), TransportError>;
+}
@@ -0,0 +1,112 @@
+use core::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use super::{AsyncSerial, Transport, TransportError};
+
+/// An in-memory transport replaying `incoming` packets and recording the last written packet.
+pub struct MockTransport<'a> {
+    incoming: &'a [&'a [u8]],
+    written: [u8; 64],
+    written_len: usize,
+}
+
+impl<'a> MockTransport<'a> {
+    pub fn new(incoming: &'a [&'a [u8]]) -> Self {
+        Self {
+            incoming,
+            written: [0; 64],
+            written_len: 0,
+        }
+    }
+
+    pub fn written(&self) -> &[u8] {
+        &self.written[..self.written_len]
+    }
+}
+
+impl Transport for MockTransport<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, TransportError> {
+        let (packet, incoming) = self.incoming.split_first().ok_or(TransportError::Io)?;
+        let target = buf
+            .get_mut(..packet.len())
+            .ok_or(TransportError::BufferOverflow)?;
+
+        target.copy_from_slice(packet);
+        self.incoming = incoming;
+
+        Ok(packet.len())
+    }
+
+    fn write(&mut self, packet: &[u8]) -> Result<(), TransportError> {
+        let target = self
+            .written
+            .get_mut(..packet.len())
+            .ok_or(TransportError::BufferOverflow)?;
+
+        target.copy_from_slice(packet);
+        self.written_len = packet.len();
+
+        Ok(())
+    }
+}
+
+/// An in-memory serial link delivering `incoming` in chunks of at most `chunk_size` bytes.
+pub struct MockSerial<'a> {
+    incoming: &'a [u8],
+    chunk_size: usize,
+    written: [u8; 64],
+    written_len: usize,
+}
+
+impl<'a> MockSerial<'a> {
+    pub fn new(incoming: &'a [u8], chunk_size: usize) -> Self {
+        Self {
+            incoming,
+            chunk_size,
+            written: [0; 64],
+            written_len: 0,
+        }
+    }
+
+    pub fn written(&self) -> &[u8] {
+        &self.written[..self.written_len]
+    }
+}
+
+impl AsyncSerial for MockSerial<'_> {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, TransportError> {
+        let len = buf.len().min(self.chunk_size).min(self.incoming.len());
+        let (chunk, incoming) = self.incoming.split_at(len);
+
+        buf[..len].copy_from_slice(chunk);
+        self.incoming = incoming;
+
+        Ok(len)
+    }
+
+    async fn write(&mut self, bytes: &[u8]) -> Result<(), TransportError> {
+        let target = self
+            .written
+            .get_mut(self.written_len..self.written_len + bytes.len())
+            .ok_or(TransportError::BufferOverflow)?;
+
+        target.copy_from_slice(bytes);
+        self.written_len += bytes.len();
+
+        Ok(())
+    }
+}
+
+/// Polls `future` to completion, the mocks never return `Poll::Pending`.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod h4;
+mod hci;
+mod interface;
+#[cfg(test)]
+pub(crate) mod mock;
+mod serial;
+
+pub use h4::*;
+pub use hci::*;
+pub use interface::*;
+pub use serial::*;
@@ -0,0 +1,130 @@
+use utils::writer::Writer;
+
+use super::{AsyncSerial, AsyncTransport, H4Decoder, TransportError};
+use crate::hci::HCIPacket;
+
+/// Implements [`AsyncTransport`] on top of an [`AsyncSerial`] by framing the byte stream with an
+/// [`H4Decoder`].
+///
+/// `N` bounds the size of a single packet, including its packet type byte.
+pub struct H4Transport<S: AsyncSerial, const N: usize> {
+    serial: S,
+    decoder: H4Decoder<N>,
+}
+
+impl<S: AsyncSerial, const N: usize> H4Transport<S, N> {
+    pub const fn new(serial: S) -> Self {
+        Self {
+            serial,
+            decoder: H4Decoder::new(),
+        }
+    }
+
+    pub fn serial(&self) -> &S {
+        &self.serial
+    }
+
+    pub fn into_serial(self) -> S {
+        self.serial
+    }
+}
+
+impl<S: AsyncSerial, const N: usize> AsyncTransport for H4Transport<S, N> {
+    async fn read<'b>(&mut self, buf: &'b mut [u8]) -> Result<HCIPacket<'b>, TransportError> {
+        let mut chunk = [0; 32];
+
+        let packet = loop {
+            // Only request the bytes the decoder is missing, so no bytes of the next packet are read
+            let len = self.decoder.needed().min(chunk.len());
+            let len = self.serial.read(&mut chunk[..len]).await?;
+            if len == 0 {
+                return Err(TransportError::Io);
+            }
+
+            self.decoder.push(&chunk[..len])?;
+            if let Some(packet) = self.decoder.packet() {
+                break packet;
+            }
+        };
+
+        let len = packet
+            .write(&mut Writer::new(buf))
+            .map_err(|_| TransportError::BufferOverflow)?;
+
+        HCIPacket::from_buf(&buf[..len]).ok_or(TransportError::Io)
+    }
+
+    async fn write(&mut self, packet: &HCIPacket<'_>) -> Result<(), TransportError> {
+        let mut buf = [0; N];
+        let len = packet
+            .write(&mut Writer::new(&mut buf))
+            .map_err(|_| TransportError::BufferOverflow)?;
+
+        self.serial.write(&buf[..len]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hci::{HCICommand, HCIEvent, fixtures::RESET_COMMAND_COMPLETE},
+        transport::mock::{MockSerial, block_on},
+    };
+
+    #[test]
+    fn test_command_event_exchange() {
+        // A UART delivering at most 3 bytes per read
+        let mut transport = H4Transport::<_, 64>::new(MockSerial::new(RESET_COMMAND_COMPLETE, 3));
+        let mut command = [0; 4];
+        let len = HCICommand::Reset.write_into(&mut command).unwrap();
+        let command = HCIPacket::from_buf(&command[..len]).unwrap();
+
+        block_on(transport.write(&command)).unwrap();
+        assert_eq!(transport.serial().written(), &[0x01, 0x03, 0x0C, 0x00]);
+
+        let mut buf = [0; 64];
+        let Ok(HCIPacket::Event(packet)) = block_on(transport.read(&mut buf)) else {
+            panic!("Unexpected packet type");
+        };
+        let Ok(HCIEvent::CommandComplete(event)) = HCIEvent::from_packet(&packet) else {
+            panic!("Unexpected event type");
+        };
+        assert_eq!(event.command_opcode, HCICommand::Reset.opcode());
+
+        assert_eq!(
+            block_on(transport.read(&mut buf)).map(|_| ()),
+            Err(TransportError::Io)
+        );
+    }
+
+    #[test]
+    fn test_read_consecutive_packets() {
+        let stream = [
+            0x04, 0x13, 0x05, 0x01, 0x40, 0x00, 0x01, 0x00, // Number Of Completed Packets
+            0x02, 0x40, 0x20, 0x01, 0x00, 0xAA, // ACL data
+        ];
+        let mut transport = H4Transport::<_, 64>::new(MockSerial::new(&stream, 32));
+        let mut buf = [0; 64];
+
+        assert!(matches!(
+            block_on(transport.read(&mut buf)),
+            Ok(HCIPacket::Event(_))
+        ));
+        let Ok(HCIPacket::ACLData(packet)) = block_on(transport.read(&mut buf)) else {
+            panic!("Unexpected packet type");
+        };
+        assert_eq!(packet.data, &[0xAA]);
+    }
+
+    #[test]
+    fn test_read_buffer_too_small() {
+        let mut transport = H4Transport::<_, 64>::new(MockSerial::new(RESET_COMMAND_COMPLETE, 32));
+        let mut buf = [0; 4];
+
+        assert_eq!(
+            block_on(transport.read(&mut buf)).map(|_| ()),
+            Err(TransportError::BufferOverflow)
+        );
+    }
+}
@@ -42,6 +42,46 @@ pub fn from_u8(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+#[proc_macro_derive(TryFromU8)]
+pub fn try_from_u8(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let data = match input.data {
+        Data::Enum(data_enum) => data_enum,
+        _ => panic!("#[derive(TryFromU8)] can only be used with enums"),
+    };
+
+    let match_arms = data.variants.into_iter().map(|v: Variant| {
+        let ident = v.ident;
+
+        let discriminant = match v.discriminant {
+            Some((_, expr)) => expr,
+            None => panic!("Enum variants must have assigned discriminant values"),
+        };
+
+        quote! {
+            #discriminant => Ok(#name::#ident),
+        }
+    });
+
+    let expanded = quote! {
+        impl TryFrom<u8> for #name {
+            type Error = u8;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match value {
+                    #(#match_arms)*
+                    _ => Err(value),
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
 #[proc_macro_derive(IntoU8)]
 pub fn into_u8(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
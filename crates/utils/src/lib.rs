@@ -2,4 +2,5 @@
 
 pub mod reader;
 pub mod slice;
+pub mod tlv;
 pub mod writer;
@@ -1,6 +1,27 @@
+use core::{error::Error, fmt::Display};
+
 use crate::slice;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ReadError {
+    BufferUnderflow,
+    InvalidUtf8,
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BufferUnderflow => write!(f, "BufferUnderflow"),
+            Self::InvalidUtf8 => write!(f, "InvalidUtf8"),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Reader<'p> {
     buf: &'p [u8],
     pub pos: usize,
@@ -12,31 +33,80 @@ impl<'p> Reader<'p> {
     }
 
     pub fn read_u8(&mut self) -> Option<u8> {
-        Some(u8::from_le_bytes(
-            self.read_u8_slice(size_of::<u8>())?.try_into().ok()?,
-        ))
+        ByteSource::read_u8(self)
     }
 
     pub fn read_u16(&mut self) -> Option<u16> {
-        Some(u16::from_le_bytes(
+        ByteSource::read_u16(self)
+    }
+
+    pub fn read_u32(&mut self) -> Option<u32> {
+        ByteSource::read_u32(self)
+    }
+
+    pub fn read_u64(&mut self) -> Option<u64> {
+        ByteSource::read_u64(self)
+    }
+
+    pub fn read_u128(&mut self) -> Option<u128> {
+        ByteSource::read_u128(self)
+    }
+
+    pub fn read_i8(&mut self) -> Option<i8> {
+        Some(i8::from_le_bytes(
+            self.read_u8_slice(size_of::<i8>())?.try_into().ok()?,
+        ))
+    }
+
+    pub fn read_i16(&mut self) -> Option<i16> {
+        Some(i16::from_le_bytes(
+            self.read_u8_slice(size_of::<i16>())?.try_into().ok()?,
+        ))
+    }
+
+    pub fn read_i32(&mut self) -> Option<i32> {
+        Some(i32::from_le_bytes(
+            self.read_u8_slice(size_of::<i32>())?.try_into().ok()?,
+        ))
+    }
+
+    /// Reads a byte that is either 0x00 or 0x01, any other value is rejected without advancing.
+    pub fn read_bool(&mut self) -> Option<bool> {
+        let value = match self.peek_u8()? {
+            0x00 => false,
+            0x01 => true,
+            _ => return None,
+        };
+
+        self.pos += size_of::<u8>();
+
+        Some(value)
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.read_u8_slice(N)?.try_into().ok()
+    }
+
+    pub fn read_u16_be(&mut self) -> Option<u16> {
+        Some(u16::from_be_bytes(
             self.read_u8_slice(size_of::<u16>())?.try_into().ok()?,
         ))
     }
 
-    pub fn read_u32(&mut self) -> Option<u32> {
-        Some(u32::from_le_bytes(
+    pub fn read_u32_be(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(
             self.read_u8_slice(size_of::<u32>())?.try_into().ok()?,
         ))
     }
 
-    pub fn read_u64(&mut self) -> Option<u64> {
-        Some(u64::from_le_bytes(
+    pub fn read_u64_be(&mut self) -> Option<u64> {
+        Some(u64::from_be_bytes(
             self.read_u8_slice(size_of::<u64>())?.try_into().ok()?,
         ))
     }
 
-    pub fn read_u128(&mut self) -> Option<u128> {
-        Some(u128::from_le_bytes(
+    pub fn read_u128_be(&mut self) -> Option<u128> {
+        Some(u128::from_be_bytes(
             self.read_u8_slice(size_of::<u128>())?.try_into().ok()?,
         ))
     }
@@ -52,6 +122,12 @@ impl<'p> Reader<'p> {
         Some(slice)
     }
 
+    /// Consumes `len` bytes and returns a reader over exactly those bytes.
+    pub fn sub_reader(&mut self, len: usize) -> Option<Reader<'p>> {
+        Some(Reader::new(self.read_u8_slice(len)?))
+    }
+
+    /// Reads `len` bytes as a slice of `u16`, `len` has to be a multiple of its size.
     pub fn read_u16_slice(&mut self, len: usize) -> Option<&'p [u16]> {
         if self.remaining() < len {
             return None;
@@ -64,6 +140,7 @@ impl<'p> Reader<'p> {
         Some(slice)
     }
 
+    /// Reads `len` bytes as a slice of `u32`, `len` has to be a multiple of its size.
     pub fn read_u32_slice(&mut self, len: usize) -> Option<&'p [u32]> {
         if self.remaining() < len {
             return None;
@@ -76,6 +153,7 @@ impl<'p> Reader<'p> {
         Some(slice)
     }
 
+    /// Reads `len` bytes as a slice of `u64`, `len` has to be a multiple of its size.
     pub fn read_u64_slice(&mut self, len: usize) -> Option<&'p [u64]> {
         if self.remaining() < len {
             return None;
@@ -88,6 +166,7 @@ impl<'p> Reader<'p> {
         Some(slice)
     }
 
+    /// Reads `len` bytes as a slice of `u128`, `len` has to be a multiple of its size.
     pub fn read_u128_slice(&mut self, len: usize) -> Option<&'p [u128]> {
         if self.remaining() < len {
             return None;
@@ -100,6 +179,40 @@ impl<'p> Reader<'p> {
         Some(slice)
     }
 
+    pub fn read_str_u8_prefixed(&mut self) -> Result<&'p str, ReadError> {
+        let start = self.pos;
+        let len = self.read_u8().ok_or(ReadError::BufferUnderflow)? as usize;
+
+        let Some(bytes) = self.read_u8_slice(len) else {
+            self.pos = start;
+            return Err(ReadError::BufferUnderflow);
+        };
+
+        core::str::from_utf8(bytes).map_err(|_| {
+            self.pos = start;
+            ReadError::InvalidUtf8
+        })
+    }
+
+    /// Reads the bytes before the next `delim` and advances past the delimiter.
+    pub fn read_until(&mut self, delim: u8) -> Option<&'p [u8]> {
+        let len = self.buf.get(self.pos..)?.iter().position(|&b| b == delim)?;
+        let slice = self.read_u8_slice(len)?;
+
+        self.pos += size_of::<u8>();
+        Some(slice)
+    }
+
+    /// Returns the next byte without advancing.
+    pub fn peek_u8(&self) -> Option<u8> {
+        self.buf.get(self.pos).copied()
+    }
+
+    /// Returns the next `len` bytes without advancing.
+    pub fn peek_u8_slice(&self, len: usize) -> Option<&'p [u8]> {
+        self.buf.get(self.pos..self.pos.checked_add(len)?)
+    }
+
     pub fn seek(&mut self, pos: usize) -> Option<usize> {
         if pos > self.buf.len() {
             return None;
@@ -114,3 +227,328 @@ impl<'p> Reader<'p> {
         self.buf.len() - self.pos
     }
 }
+
+/// A source of bytes that the integer reads are expressed in terms of, so they can be used with
+/// any backing storage.
+///
+/// The little endian reads are implemented once here, the inherent [`Reader`] methods of the same
+/// name forward to them. Bytes are borrowed from the underlying buffer rather than from the
+/// source, so zero-copy parsers can be written generically over `impl ByteSource<'p>`.
+pub trait ByteSource<'p> {
+    fn read_bytes(&mut self, len: usize) -> Option<&'p [u8]>;
+
+    fn remaining(&self) -> usize;
+
+    fn read_u8(&mut self) -> Option<u8> {
+        Some(u8::from_le_bytes(
+            self.read_bytes(size_of::<u8>())?.try_into().ok()?,
+        ))
+    }
+
+    fn read_u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(
+            self.read_bytes(size_of::<u16>())?.try_into().ok()?,
+        ))
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(
+            self.read_bytes(size_of::<u32>())?.try_into().ok()?,
+        ))
+    }
+
+    fn read_u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(
+            self.read_bytes(size_of::<u64>())?.try_into().ok()?,
+        ))
+    }
+
+    fn read_u128(&mut self) -> Option<u128> {
+        Some(u128::from_le_bytes(
+            self.read_bytes(size_of::<u128>())?.try_into().ok()?,
+        ))
+    }
+}
+
+impl<'p> ByteSource<'p> for Reader<'p> {
+    fn read_bytes(&mut self, len: usize) -> Option<&'p [u8]> {
+        self.read_u8_slice(len)
+    }
+
+    fn remaining(&self) -> usize {
+        Reader::remaining(self)
+    }
+}
+
+impl<'p> ByteSource<'p> for &'p [u8] {
+    fn read_bytes(&mut self, len: usize) -> Option<&'p [u8]> {
+        if self.len() < len {
+            return None;
+        }
+
+        let (head, tail) = self.split_at(len);
+        *self = tail;
+
+        Some(head)
+    }
+
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(align(2))]
+    struct Aligned([u8; 5]);
+
+    #[test]
+    fn test_read_u16_slice_unaligned() {
+        let buf = Aligned([0x00, 0x01, 0x02, 0x03, 0x04]);
+
+        assert_eq!(
+            Reader::new(&buf.0).read_u16_slice(4).map(|s| s.len()),
+            Some(2)
+        );
+
+        let mut reader = Reader::new(&buf.0);
+        reader.read_u8().unwrap();
+
+        assert_eq!(reader.read_u16_slice(4), None);
+    }
+
+    #[test]
+    fn test_sub_reader() {
+        let mut reader = Reader::new(&[0x01, 0x02, 0x03, 0x04, 0x05]);
+        reader.read_u8().unwrap();
+
+        let mut sub_reader = reader.sub_reader(3).unwrap();
+
+        assert_eq!(reader.pos, 4);
+        assert_eq!(sub_reader.pos, 0);
+        assert_eq!(sub_reader.remaining(), 3);
+        assert_eq!(sub_reader.read_u16(), Some(0x0302));
+        assert_eq!(sub_reader.read_u16(), None);
+        assert_eq!(reader.read_u8(), Some(0x05));
+        assert!(reader.sub_reader(1).is_none());
+        assert_eq!(reader.sub_reader(0).map(|r| r.remaining()), Some(0));
+    }
+
+    #[test]
+    fn test_peek_does_not_advance() {
+        let mut reader = Reader::new(&[0x02, 0x01, 0x06]);
+
+        assert_eq!(reader.peek_u8(), Some(0x02));
+        assert_eq!(reader.peek_u8_slice(2), Some(&[0x02, 0x01][..]));
+        assert_eq!(reader.pos, 0);
+
+        reader.read_u8().unwrap();
+
+        assert_eq!(reader.peek_u8_slice(2), Some(&[0x01, 0x06][..]));
+        assert_eq!(reader.pos, 1);
+    }
+
+    #[test]
+    fn test_peek_past_end() {
+        let mut reader = Reader::new(&[0x01]);
+
+        assert_eq!(reader.peek_u8_slice(2), None);
+
+        reader.read_u8().unwrap();
+
+        assert_eq!(reader.peek_u8(), None);
+        assert_eq!(reader.peek_u8_slice(0), Some(&[][..]));
+        assert_eq!(reader.peek_u8_slice(1), None);
+    }
+
+    #[test]
+    fn test_read_signed() {
+        // -40 dBm, -300 and -70000 in two's complement
+        let buf = [0xD8, 0xD4, 0xFE, 0x90, 0xEE, 0xFE, 0xFF];
+        let mut reader = Reader::new(&buf);
+
+        assert_eq!(reader.read_i8(), Some(-40));
+        assert_eq!(reader.read_i16(), Some(-300));
+        assert_eq!(reader.read_i32(), Some(-70000));
+        assert_eq!(reader.read_i8(), None);
+        assert_eq!(Reader::new(&[0x7F]).read_i8(), Some(127));
+    }
+
+    #[test]
+    fn test_read_bool() {
+        let mut reader = Reader::new(&[0x00, 0x01, 0x02]);
+
+        assert_eq!(reader.read_bool(), Some(false));
+        assert_eq!(reader.read_bool(), Some(true));
+        assert_eq!(reader.read_bool(), None);
+        assert_eq!(reader.pos, 2);
+        assert_eq!(reader.read_u8(), Some(0x02));
+        assert_eq!(reader.read_bool(), None);
+    }
+
+    #[test]
+    fn test_read_array() {
+        let buf: [u8; 10] = core::array::from_fn(|i| i as u8);
+        let mut reader = Reader::new(&buf);
+
+        assert_eq!(
+            reader.read_array(),
+            Some([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07])
+        );
+        assert_eq!(reader.read_array::<0>(), Some([]));
+        assert_eq!(reader.read_array::<3>(), None);
+        assert_eq!(reader.pos, 8);
+        assert_eq!(reader.read_array(), Some([0x08, 0x09]));
+    }
+
+    #[test]
+    fn test_read_u32_be() {
+        let buf = [0x01, 0x02, 0x03, 0x04];
+
+        let mut le = Reader::new(&buf);
+        let mut be = Reader::new(&buf);
+
+        assert_eq!(le.read_u32(), Some(0x04030201));
+        assert_eq!(be.read_u32_be(), Some(0x01020304));
+        assert_eq!(be.pos, le.pos);
+        assert_eq!(be.read_u32_be(), None);
+    }
+
+    #[test]
+    fn test_read_be_widths() {
+        let buf: [u8; 16] = core::array::from_fn(|i| i as u8);
+
+        assert_eq!(Reader::new(&buf).read_u16_be(), Some(0x0001));
+        assert_eq!(Reader::new(&buf).read_u64_be(), Some(0x0001020304050607));
+        assert_eq!(
+            Reader::new(&buf).read_u128_be(),
+            Some(0x000102030405060708090A0B0C0D0E0F)
+        );
+    }
+
+    #[test]
+    fn test_read_str_u8_prefixed() {
+        let mut reader = Reader::new(&[0x03, b'b', b'l', b'e', 0x00]);
+
+        assert_eq!(reader.read_str_u8_prefixed(), Ok("ble"));
+        assert_eq!(reader.pos, 4);
+        assert_eq!(reader.read_str_u8_prefixed(), Ok(""));
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn test_read_str_u8_prefixed_invalid_utf8() {
+        let mut reader = Reader::new(&[0x02, 0xC3, 0x28]);
+
+        assert_eq!(reader.read_str_u8_prefixed(), Err(ReadError::InvalidUtf8));
+        assert_eq!(reader.pos, 0);
+    }
+
+    #[test]
+    fn test_read_str_u8_prefixed_truncated() {
+        let mut reader = Reader::new(&[0x05, b'b', b'l', b'e']);
+
+        assert_eq!(
+            reader.read_str_u8_prefixed(),
+            Err(ReadError::BufferUnderflow)
+        );
+        assert_eq!(reader.pos, 0);
+
+        let mut reader = Reader::new(&[]);
+
+        assert_eq!(
+            reader.read_str_u8_prefixed(),
+            Err(ReadError::BufferUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_read_until() {
+        let mut reader = Reader::new(b"ble\0rest");
+
+        assert_eq!(reader.read_until(0x00), Some(&b"ble"[..]));
+        assert_eq!(reader.pos, 4);
+        assert_eq!(reader.remaining(), 4);
+    }
+
+    #[test]
+    fn test_read_until_delimiter_first() {
+        let mut reader = Reader::new(&[0x00, 0x01]);
+
+        assert_eq!(reader.read_until(0x00), Some(&[][..]));
+        assert_eq!(reader.pos, 1);
+    }
+
+    #[test]
+    fn test_read_until_missing_delimiter() {
+        let mut reader = Reader::new(&[0x01, 0x02, 0x03]);
+
+        assert_eq!(reader.read_until(0x00), None);
+        assert_eq!(reader.pos, 0);
+    }
+
+    #[test]
+    fn test_read_until_past_end() {
+        let mut reader = Reader::new(&[0x01, 0x00]);
+        reader.pos = 3;
+
+        assert_eq!(reader.read_until(0x00), None);
+        assert_eq!(reader.pos, 3);
+    }
+
+    #[test]
+    fn test_byte_source_reader() {
+        let mut reader = Reader::new(&[0x01, 0x02, 0x03]);
+
+        assert_eq!(ByteSource::read_u16(&mut reader), Some(0x0201));
+        assert_eq!(ByteSource::remaining(&reader), 1);
+        assert_eq!(reader.pos, 2);
+    }
+
+    #[test]
+    fn test_byte_source_slice() {
+        let mut source: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05];
+
+        assert_eq!(source.read_u32(), Some(0x04030201));
+        assert_eq!(source.read_u16(), None);
+        assert_eq!(source.read_u8(), Some(0x05));
+        assert_eq!(source.remaining(), 0);
+    }
+
+    fn read_name<'p>(source: &mut impl ByteSource<'p>) -> Option<&'p [u8]> {
+        let len = source.read_u8()?;
+        source.read_bytes(len as usize)
+    }
+
+    #[test]
+    fn test_byte_source_borrows_from_buffer() {
+        let buf = [0x03, b'b', b'l', b'e', 0x01];
+
+        let name = {
+            let mut reader = Reader::new(&buf);
+            read_name(&mut reader)
+        };
+        assert_eq!(name, Some(&b"ble"[..]));
+
+        let name = {
+            let mut source: &[u8] = &buf;
+            read_name(&mut source)
+        };
+        assert_eq!(name, Some(&b"ble"[..]));
+    }
+
+    #[test]
+    fn test_inherent_reads_match_byte_source() {
+        let buf = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let mut reader = Reader::new(&buf);
+        let mut source: &[u8] = &buf;
+
+        assert_eq!(reader.read_u16(), ByteSource::read_u16(&mut source));
+        assert_eq!(reader.read_u8(), ByteSource::read_u8(&mut source));
+        assert_eq!(reader.read_u32(), ByteSource::read_u32(&mut source));
+        assert_eq!(reader.read_u16(), ByteSource::read_u16(&mut source));
+        assert_eq!(reader.remaining(), ByteSource::remaining(&source));
+    }
+}
@@ -1,30 +1,75 @@
-const unsafe fn as_slice<T, U>(slice: &[U]) -> Option<&[T]> {
-    if slice.len() % size_of::<T>() != 0 {
+// The alignment check needs the address of the slice, which is not available during const
+// evaluation, so only the casts to `u8` (always aligned) can stay `const`.
+unsafe fn as_slice<T, U>(slice: &[U]) -> Option<&[T]> {
+    let len = size_of_val(slice);
+
+    if !len.is_multiple_of(size_of::<T>()) {
+        return None;
+    }
+
+    if !slice.as_ptr().cast::<T>().is_aligned() {
         return None;
     }
 
     Some(core::slice::from_raw_parts(
         slice.as_ptr() as *const T,
-        slice.len() / size_of::<T>(),
+        len / size_of::<T>(),
     ))
 }
 
 pub const fn as_u8_slice<T>(slice: &[T]) -> Option<&[u8]> {
-    unsafe { as_slice(slice) }
+    unsafe {
+        Some(core::slice::from_raw_parts(
+            slice.as_ptr() as *const u8,
+            size_of_val(slice),
+        ))
+    }
 }
 
-pub const fn as_u16_slice<T>(slice: &[T]) -> Option<&[u16]> {
+pub fn as_u16_slice<T>(slice: &[T]) -> Option<&[u16]> {
     unsafe { as_slice(slice) }
 }
 
-pub const fn as_u32_slice<T>(slice: &[T]) -> Option<&[u32]> {
+pub fn as_u32_slice<T>(slice: &[T]) -> Option<&[u32]> {
     unsafe { as_slice(slice) }
 }
 
-pub const fn as_u64_slice<T>(slice: &[T]) -> Option<&[u64]> {
+pub fn as_u64_slice<T>(slice: &[T]) -> Option<&[u64]> {
     unsafe { as_slice(slice) }
 }
 
-pub const fn as_u128_slice<T>(slice: &[T]) -> Option<&[u128]> {
+pub fn as_u128_slice<T>(slice: &[T]) -> Option<&[u128]> {
     unsafe { as_slice(slice) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(align(4))]
+    struct Aligned([u8; 9]);
+
+    #[test]
+    fn test_as_u8_slice_const() {
+        const BYTES: Option<&[u8]> = as_u8_slice(&[0x0201u16, 0x0403]);
+
+        assert_eq!(BYTES, Some(&[0x01, 0x02, 0x03, 0x04][..]));
+    }
+
+    #[test]
+    fn test_as_u16_slice_misaligned() {
+        let buf = Aligned([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+
+        assert_eq!(as_u16_slice(&buf.0[..4]).map(|s| s.len()), Some(2));
+        assert_eq!(as_u16_slice(&buf.0[1..5]), None);
+    }
+
+    #[test]
+    fn test_as_u32_slice_misaligned() {
+        let buf = Aligned([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+
+        assert_eq!(as_u32_slice(&buf.0[..8]).map(|s| s.len()), Some(2));
+        assert_eq!(as_u32_slice(&buf.0[2..6]), None);
+        assert_eq!(as_u32_slice(&buf.0[..6]), None);
+    }
+}
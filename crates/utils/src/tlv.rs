@@ -0,0 +1,136 @@
+use crate::reader::Reader;
+
+/// The width of the length field in front of every element, read little endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LengthField {
+    U8,
+    U16,
+}
+
+/// Iterates over length-tag-value elements, yielding the tag and the value of each.
+///
+/// Every element starts with its length followed by a one byte tag. If `length_includes_tag` is
+/// set the length counts the tag byte as well, as in advertising and EIR data, and a zero length
+/// ends the iteration since it is the start of the padding. A truncated element ends the
+/// iteration as well, in both cases the rest of the buffer is skipped.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TlvIterator<'p> {
+    reader: Reader<'p>,
+    length_field: LengthField,
+    length_includes_tag: bool,
+}
+
+impl<'p> TlvIterator<'p> {
+    pub fn new(reader: Reader<'p>, length_field: LengthField, length_includes_tag: bool) -> Self {
+        Self {
+            reader,
+            length_field,
+            length_includes_tag,
+        }
+    }
+
+    /// The reader positioned at the next element.
+    pub fn reader(&self) -> &Reader<'p> {
+        &self.reader
+    }
+
+    fn read_element(&mut self) -> Option<(u8, &'p [u8])> {
+        let len = match self.length_field {
+            LengthField::U8 => self.reader.read_u8()? as usize,
+            LengthField::U16 => self.reader.read_u16()? as usize,
+        };
+
+        let len = match self.length_includes_tag {
+            true => len.checked_sub(size_of::<u8>())?,
+            false => len,
+        };
+
+        Some((self.reader.read_u8()?, self.reader.read_u8_slice(len)?))
+    }
+}
+
+impl<'p> Iterator for TlvIterator<'p> {
+    type Item = (u8, &'p [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.remaining() == 0 {
+            return None;
+        }
+
+        let element = self.read_element();
+
+        if element.is_none() {
+            self.reader.read_u8_slice(self.reader.remaining());
+        }
+
+        element
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_includes_tag() {
+        let buf = [0x02, 0x01, 0x06, 0x03, 0xFF, 0x59, 0x00, 0x01, 0x08];
+        let mut tlv = TlvIterator::new(Reader::new(&buf), LengthField::U8, true);
+
+        assert_eq!(tlv.next(), Some((0x01, &[0x06][..])));
+        assert_eq!(tlv.next(), Some((0xFF, &[0x59, 0x00][..])));
+        assert_eq!(tlv.next(), Some((0x08, &[][..])));
+        assert_eq!(tlv.next(), None);
+    }
+
+    #[test]
+    fn test_zero_length_ends_iteration() {
+        let buf = [0x02, 0x01, 0x06, 0x00, 0x00, 0x00];
+        let mut tlv = TlvIterator::new(Reader::new(&buf), LengthField::U8, true);
+
+        assert_eq!(tlv.next(), Some((0x01, &[0x06][..])));
+        assert_eq!(tlv.next(), None);
+        assert_eq!(tlv.next(), None);
+    }
+
+    #[test]
+    fn test_length_excludes_tag() {
+        let buf = [0x01, 0x00, 0x01, 0x06, 0x00, 0x00, 0x02];
+        let mut tlv = TlvIterator::new(Reader::new(&buf), LengthField::U16, false);
+
+        assert_eq!(tlv.next(), Some((0x01, &[0x06][..])));
+        assert_eq!(tlv.next(), Some((0x02, &[][..])));
+        assert_eq!(tlv.next(), None);
+    }
+
+    #[test]
+    fn test_u16_length_includes_tag() {
+        let buf = [0x03, 0x00, 0x10, 0xAA, 0xBB];
+        let mut tlv = TlvIterator::new(Reader::new(&buf), LengthField::U16, true);
+
+        assert_eq!(tlv.next(), Some((0x10, &[0xAA, 0xBB][..])));
+        assert_eq!(tlv.next(), None);
+    }
+
+    #[test]
+    fn test_truncated_element() {
+        let buf = [0x02, 0x01, 0x06, 0x05, 0x09, b'b', b'l'];
+        let mut tlv = TlvIterator::new(Reader::new(&buf), LengthField::U8, true);
+
+        assert_eq!(tlv.next(), Some((0x01, &[0x06][..])));
+        assert_eq!(tlv.next(), None);
+        assert_eq!(tlv.reader().remaining(), 0);
+        assert_eq!(tlv.next(), None);
+    }
+
+    #[test]
+    fn test_truncated_length_field() {
+        let buf = [0x01, 0x00, 0x01, 0x06, 0x01];
+        let mut tlv = TlvIterator::new(Reader::new(&buf), LengthField::U16, false);
+
+        assert_eq!(tlv.next(), Some((0x01, &[0x06][..])));
+        assert_eq!(tlv.next(), None);
+        assert_eq!(tlv.reader().remaining(), 0);
+    }
+}
@@ -31,27 +31,43 @@ impl<'p> Writer<'p> {
     }
 
     pub fn write_u8(&mut self, value: u8) -> Result<(), WriteError> {
-        self.write_u8_slice(&value.to_le_bytes())
+        ByteSink::write_u8(self, value)
     }
 
     pub fn write_u16(&mut self, value: u16) -> Result<(), WriteError> {
-        self.write_u8_slice(&value.to_le_bytes())
+        ByteSink::write_u16(self, value)
     }
 
     pub fn write_u32(&mut self, value: u32) -> Result<(), WriteError> {
-        self.write_u8_slice(&value.to_le_bytes())
+        ByteSink::write_u32(self, value)
     }
 
     pub fn write_u64(&mut self, value: u64) -> Result<(), WriteError> {
-        self.write_u8_slice(&value.to_le_bytes())
+        ByteSink::write_u64(self, value)
     }
 
     pub fn write_u128(&mut self, value: u128) -> Result<(), WriteError> {
-        self.write_u8_slice(&value.to_le_bytes())
+        ByteSink::write_u128(self, value)
+    }
+
+    pub fn write_u16_be(&mut self, value: u16) -> Result<(), WriteError> {
+        self.write_u8_slice(&value.to_be_bytes())
+    }
+
+    pub fn write_u32_be(&mut self, value: u32) -> Result<(), WriteError> {
+        self.write_u8_slice(&value.to_be_bytes())
+    }
+
+    pub fn write_u64_be(&mut self, value: u64) -> Result<(), WriteError> {
+        self.write_u8_slice(&value.to_be_bytes())
+    }
+
+    pub fn write_u128_be(&mut self, value: u128) -> Result<(), WriteError> {
+        self.write_u8_slice(&value.to_be_bytes())
     }
 
     pub fn write_u8_slice(&mut self, slice: &[u8]) -> Result<(), WriteError> {
-        if self.pos + slice.len() >= self.buf.len() {
+        if self.pos + slice.len() > self.buf.len() {
             return Err(WriteError::BufferOverflow);
         }
 
@@ -62,66 +78,383 @@ impl<'p> Writer<'p> {
     }
 
     pub fn write_u16_slice(&mut self, slice: &[u16]) -> Result<(), WriteError> {
-        if self.pos + slice.len() >= self.buf.len() {
-            return Err(WriteError::BufferOverflow);
+        match slice::as_u8_slice(slice) {
+            Some(slice) => self.write_u8_slice(slice),
+            None => Err(WriteError::BufferOverflow),
         }
+    }
 
-        let slice = match slice::as_u8_slice(slice) {
-            Some(slice) => slice,
-            None => return Err(WriteError::BufferOverflow),
-        };
+    pub fn write_u32_slice(&mut self, slice: &[u32]) -> Result<(), WriteError> {
+        match slice::as_u8_slice(slice) {
+            Some(slice) => self.write_u8_slice(slice),
+            None => Err(WriteError::BufferOverflow),
+        }
+    }
 
-        self.buf[self.pos..(self.pos + slice.len())].copy_from_slice(slice);
-        self.pos += slice.len();
+    pub fn write_u64_slice(&mut self, slice: &[u64]) -> Result<(), WriteError> {
+        match slice::as_u8_slice(slice) {
+            Some(slice) => self.write_u8_slice(slice),
+            None => Err(WriteError::BufferOverflow),
+        }
+    }
 
-        Ok(())
+    pub fn write_u128_slice(&mut self, slice: &[u128]) -> Result<(), WriteError> {
+        match slice::as_u8_slice(slice) {
+            Some(slice) => self.write_u8_slice(slice),
+            None => Err(WriteError::BufferOverflow),
+        }
     }
 
-    pub fn write_u32_slice(&mut self, slice: &[u32]) -> Result<(), WriteError> {
-        if self.pos + slice.len() >= self.buf.len() {
-            return Err(WriteError::BufferOverflow);
+    /// The current position, to patch a reserved field with [`Writer::write_u8_at`] once the bytes
+    /// following it are written, or to undo them with [`Writer::rollback`].
+    pub fn checkpoint(&self) -> usize {
+        self.pos
+    }
+
+    pub fn rollback(&mut self, checkpoint: usize) {
+        self.pos = self.pos.min(checkpoint);
+    }
+
+    pub fn write_u8_at(&mut self, pos: usize, value: u8) -> Result<(), WriteError> {
+        self.write_u8_slice_at(pos, &value.to_le_bytes())
+    }
+
+    pub fn write_u16_at(&mut self, pos: usize, value: u16) -> Result<(), WriteError> {
+        self.write_u8_slice_at(pos, &value.to_le_bytes())
+    }
+
+    // Only the already written region can be patched, the cursor is left where it is
+    fn write_u8_slice_at(&mut self, pos: usize, slice: &[u8]) -> Result<(), WriteError> {
+        match pos.checked_add(slice.len()) {
+            Some(end) if end <= self.pos => {
+                self.buf[pos..end].copy_from_slice(slice);
+                Ok(())
+            }
+            _ => Err(WriteError::BufferOverflow),
         }
+    }
 
-        let slice = match slice::as_u8_slice(slice) {
-            Some(slice) => slice,
-            None => return Err(WriteError::BufferOverflow),
-        };
+    pub fn as_written(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
 
-        self.buf[self.pos..(self.pos + slice.len())].copy_from_slice(slice);
-        self.pos += slice.len();
+    /// Gives back the part of the buffer that has been written, with the lifetime of the buffer
+    /// rather than of the writer.
+    pub fn finish(self) -> &'p mut [u8] {
+        &mut self.buf[..self.pos]
+    }
 
-        Ok(())
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
     }
 
-    pub fn write_u64_slice(&mut self, slice: &[u64]) -> Result<(), WriteError> {
-        if self.pos + slice.len() >= self.buf.len() {
+    /// Rewinds to the start of the buffer so it can be reused for the next frame. The bytes
+    /// already written are left in place and overwritten by the following writes.
+    pub fn reset(&mut self) {
+        self.pos = 0;
+    }
+}
+
+/// A sink for bytes that the integer writes are expressed in terms of, so they can be used with
+/// any backing storage.
+///
+/// The little endian writes are implemented once here, the inherent [`Writer`] methods of the
+/// same name forward to them.
+pub trait ByteSink {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WriteError>;
+
+    fn write_u8(&mut self, value: u8) -> Result<(), WriteError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    fn write_u16(&mut self, value: u16) -> Result<(), WriteError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, value: u32) -> Result<(), WriteError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, value: u64) -> Result<(), WriteError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    fn write_u128(&mut self, value: u128) -> Result<(), WriteError> {
+        self.write_bytes(&value.to_le_bytes())
+    }
+}
+
+impl ByteSink for Writer<'_> {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WriteError> {
+        self.write_u8_slice(bytes)
+    }
+}
+
+impl ByteSink for &mut [u8] {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WriteError> {
+        if self.len() < bytes.len() {
             return Err(WriteError::BufferOverflow);
         }
 
-        let slice = match slice::as_u8_slice(slice) {
-            Some(slice) => slice,
-            None => return Err(WriteError::BufferOverflow),
-        };
-
-        self.buf[self.pos..(self.pos + slice.len())].copy_from_slice(slice);
-        self.pos += slice.len();
+        let (head, tail) = core::mem::take(self).split_at_mut(bytes.len());
+        head.copy_from_slice(bytes);
+        *self = tail;
 
         Ok(())
     }
+}
 
-    pub fn write_u128_slice(&mut self, slice: &[u128]) -> Result<(), WriteError> {
-        if self.pos + slice.len() >= self.buf.len() {
-            return Err(WriteError::BufferOverflow);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::Reader;
+
+    struct RingSink {
+        buf: [u8; 4],
+        pos: usize,
+    }
+
+    impl ByteSink for RingSink {
+        fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WriteError> {
+            for byte in bytes {
+                self.buf[self.pos % self.buf.len()] = *byte;
+                self.pos += 1;
+            }
+
+            Ok(())
         }
+    }
 
-        let slice = match slice::as_u8_slice(slice) {
-            Some(slice) => slice,
-            None => return Err(WriteError::BufferOverflow),
+    #[test]
+    fn test_byte_sink_custom() {
+        let mut sink = RingSink {
+            buf: [0; 4],
+            pos: 0,
         };
 
-        self.buf[self.pos..(self.pos + slice.len())].copy_from_slice(slice);
-        self.pos += slice.len();
+        sink.write_u16(0x0201).unwrap();
+        sink.write_u32(0x06050403).unwrap();
 
-        Ok(())
+        assert_eq!(sink.pos, 6);
+        assert_eq!(sink.buf, [0x05, 0x06, 0x03, 0x04]);
+    }
+
+    #[test]
+    fn test_byte_sink_slice() {
+        let mut buf = [0; 3];
+        let mut sink: &mut [u8] = &mut buf;
+
+        sink.write_u16(0x0201).unwrap();
+        assert!(sink.write_u16(0x0403).is_err());
+        sink.write_u8(0x03).unwrap();
+        assert!(sink.is_empty());
+
+        assert_eq!(buf, [0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn test_write_exactly_to_capacity() {
+        let mut buf = [0; 4];
+        let mut writer = Writer::new(&mut buf);
+
+        assert!(writer.write_u8_slice(&[0x01, 0x02, 0x03, 0x04]).is_ok());
+        assert_eq!(writer.pos, 4);
+        assert_eq!(buf, [0x01, 0x02, 0x03, 0x04]);
+    }
+
+    #[test]
+    fn test_write_integers_exactly_to_capacity() {
+        let mut buf = [0; 7];
+        let mut writer = Writer::new(&mut buf);
+
+        assert!(writer.write_u8(0x01).is_ok());
+        assert!(writer.write_u16(0x0302).is_ok());
+        assert!(writer.write_u32(0x07060504).is_ok());
+        assert_eq!(writer.pos, 7);
+        assert_eq!(buf, [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]);
+    }
+
+    #[test]
+    fn test_back_patch_length() {
+        let mut buf = [0; 16];
+        let mut writer = Writer::new(&mut buf);
+
+        writer.write_u8(0x04).unwrap();
+        let length = writer.checkpoint();
+        writer.write_u8(0x00).unwrap();
+        let start = writer.checkpoint();
+        writer
+            .write_u8_slice(&[0x01, 0x02, 0x03, 0x04, 0x05])
+            .unwrap();
+        let body_len = (writer.checkpoint() - start) as u8;
+
+        writer.write_u8_at(length, body_len).unwrap();
+
+        assert_eq!(writer.pos, 7);
+        assert_eq!(
+            writer.as_written(),
+            &[0x04, 0x05, 0x01, 0x02, 0x03, 0x04, 0x05]
+        );
+    }
+
+    #[test]
+    fn test_write_at_outside_written_region() {
+        let mut buf = [0; 8];
+        let mut writer = Writer::new(&mut buf);
+
+        writer.write_u16(0x0000).unwrap();
+        writer.write_u8(0xAA).unwrap();
+
+        assert!(writer.write_u16_at(1, 0x0201).is_ok());
+        assert!(matches!(
+            writer.write_u16_at(2, 0x0403),
+            Err(WriteError::BufferOverflow)
+        ));
+        assert!(matches!(
+            writer.write_u8_at(usize::MAX, 0x05),
+            Err(WriteError::BufferOverflow)
+        ));
+        assert_eq!(writer.as_written(), &[0x00, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut buf = [0; 8];
+        let mut writer = Writer::new(&mut buf);
+
+        writer.write_u8(0x01).unwrap();
+        let checkpoint = writer.checkpoint();
+        writer.write_u32(0x05040302).unwrap();
+        writer.rollback(checkpoint);
+
+        assert_eq!(writer.as_written(), &[0x01]);
+
+        // Rolling forward past the written region is not possible
+        writer.rollback(4);
+        assert_eq!(writer.pos, 1);
+    }
+
+    #[test]
+    fn test_as_written() {
+        let mut buf = [0; 16];
+        let mut writer = Writer::new(&mut buf);
+
+        assert_eq!(writer.as_written(), &[]);
+
+        // HCI_LE_Set_Scan_Enable command packet with scanning enabled
+        writer.write_u8(0x01).unwrap();
+        writer.write_u16(0x200C).unwrap();
+        writer.write_u8(0x02).unwrap();
+        writer.write_u8_slice(&[0x01, 0x00]).unwrap();
+
+        assert_eq!(writer.as_written(), &[0x01, 0x0C, 0x20, 0x02, 0x01, 0x00]);
+
+        let written = writer.finish();
+        written[5] = 0x01;
+        assert_eq!(written, &[0x01, 0x0C, 0x20, 0x02, 0x01, 0x01]);
+    }
+
+    #[test]
+    fn test_remaining_and_reset() {
+        let mut buf = [0; 8];
+        let mut writer = Writer::new(&mut buf);
+
+        assert_eq!(writer.remaining(), 8);
+        writer.write_u8(0x01).unwrap();
+        assert_eq!(writer.remaining(), 7);
+        writer.write_u32(0x05040302).unwrap();
+        assert_eq!(writer.remaining(), 3);
+        assert!(writer.write_u32(0x09080706).is_err());
+        assert_eq!(writer.remaining(), 3);
+
+        writer.reset();
+        assert_eq!(writer.pos, 0);
+        assert_eq!(writer.remaining(), 8);
+
+        writer.write_u16(0xBBAA).unwrap();
+        assert_eq!(writer.remaining(), 6);
+        assert_eq!(buf[..5], [0xAA, 0xBB, 0x03, 0x04, 0x05]);
+    }
+
+    #[test]
+    fn test_write_one_byte_too_many() {
+        let mut buf = [0; 4];
+        let mut writer = Writer::new(&mut buf);
+
+        writer.write_u32(0x04030201).unwrap();
+
+        assert!(matches!(
+            writer.write_u8(0x05),
+            Err(WriteError::BufferOverflow)
+        ));
+        assert_eq!(writer.pos, 4);
+    }
+
+    #[test]
+    fn test_write_be_round_trip() {
+        let mut buf = [0; 30];
+        let mut writer = Writer::new(&mut buf);
+
+        writer.write_u16_be(0x0102).unwrap();
+        writer.write_u32_be(0x03040506).unwrap();
+        writer.write_u64_be(0x0708090A0B0C0D0E).unwrap();
+        writer
+            .write_u128_be(0x0F101112131415161718191A1B1C1D1E)
+            .unwrap();
+        assert_eq!(writer.pos, 30);
+        assert_eq!(&buf[..4], &[0x01, 0x02, 0x03, 0x04]);
+
+        let mut reader = Reader::new(&buf);
+
+        assert_eq!(reader.read_u16_be(), Some(0x0102));
+        assert_eq!(reader.read_u32_be(), Some(0x03040506));
+        assert_eq!(reader.read_u64_be(), Some(0x0708090A0B0C0D0E));
+        assert_eq!(
+            reader.read_u128_be(),
+            Some(0x0F101112131415161718191A1B1C1D1E)
+        );
+    }
+
+    #[test]
+    fn test_write_be_overflow() {
+        let mut buf = [0; 3];
+        let mut writer = Writer::new(&mut buf);
+
+        writer.write_u16_be(0x0102).unwrap();
+
+        assert!(matches!(
+            writer.write_u16_be(0x0304),
+            Err(WriteError::BufferOverflow)
+        ));
+        assert_eq!(writer.pos, 2);
+    }
+
+    #[repr(align(4))]
+    struct Aligned([u8; 12]);
+
+    #[test]
+    fn test_write_u32_slice_round_trip() {
+        let mut buf = Aligned([0; 12]);
+        let mut writer = Writer::new(&mut buf.0);
+
+        writer.write_u32_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(writer.pos, 12);
+
+        let mut reader = Reader::new(&buf.0);
+
+        assert_eq!(reader.read_u32_slice(12), Some(&[1, 2, 3][..]));
+        assert_eq!(reader.pos, 12);
+    }
+
+    #[test]
+    fn test_write_u32_slice_overflow() {
+        let mut buf = [0; 11];
+        let mut writer = Writer::new(&mut buf);
+
+        assert!(matches!(
+            writer.write_u32_slice(&[1, 2, 3]),
+            Err(WriteError::BufferOverflow)
+        ));
+        assert_eq!(writer.pos, 0);
     }
 }